@@ -0,0 +1,214 @@
+//! Numerical differentiation
+
+use crate::integral::Estimate;
+use alloc::vec::Vec;
+use num::{traits::real::Real, Complex, FromPrimitive};
+
+/// Derivative of order `order` of function `f` at `x`.
+pub struct Derivative<F, T> {
+    x: T,
+    order: usize,
+    f: F,
+}
+
+impl<F, T> Derivative<F, T> {
+    pub fn new(x: T, order: usize, f: F) -> Self {
+        Self { x, order, f }
+    }
+}
+
+impl<F, T> Derivative<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+{
+    /// Approximate the derivative with a
+    /// [central difference](https://en.wikipedia.org/wiki/Finite_difference#Higher-order_differences),
+    /// with a step of `epsilon^(1 / (order + 2))` scaled by `x` to balance truncation and rounding error.
+    ///
+    /// ```
+    /// use quickmaths::derivative::Derivative;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut derivative = Derivative::new(1., 1, |x: f64| x.sin());
+    /// assert_relative_eq!(derivative.central(), 1f64.cos(), epsilon = 1e-10);
+    ///
+    /// let mut derivative = Derivative::new(1., 2, |x: f64| x.sin());
+    /// assert_relative_eq!(derivative.central(), -1f64.sin(), epsilon = 1e-6);
+    /// ```
+    pub fn central(&mut self) -> T {
+        let step = self.step(self.order + 2);
+        self.central_difference(step)
+    }
+
+    /// Approximate the derivative with a
+    /// [forward difference](https://en.wikipedia.org/wiki/Finite_difference#Higher-order_differences),
+    /// only evaluating `f` at or above `x`, with a step of `epsilon^(1 / (order + 1))` scaled by `x`.
+    ///
+    /// ```
+    /// use quickmaths::derivative::Derivative;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // The square root is only defined from zero
+    /// let mut derivative = Derivative::new(0.25, 1, |x: f64| x.sqrt());
+    /// assert_relative_eq!(derivative.forward(), 1., epsilon = 1e-7);
+    /// ```
+    pub fn forward(&mut self) -> T {
+        let step = self.step(self.order + 1);
+        let n = self.order;
+        let sum = (0..=n).fold(T::zero(), |sum, k| {
+            let term = binomial::<T>(n, k) * (self.f)(self.x + step * T::from_usize(k).unwrap());
+            if (n - k) % 2 == 1 {
+                sum - term
+            } else {
+                sum + term
+            }
+        });
+        sum / step.powi(n as i32)
+    }
+
+    /// Approximate the derivative with [Ridders' method](https://doi.org/10.1016/S0965-9978(82)80027-0),
+    /// extrapolating central differences of decreasing step to zero step.
+    ///
+    /// ```
+    /// use quickmaths::derivative::{Derivative, Richardson};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut derivative = Derivative::new(1., 1, |x: f64| x.exp());
+    /// let estimate = derivative.richardson(Richardson::default());
+    /// assert_relative_eq!(estimate.value, 1f64.exp(), epsilon = 1e-12);
+    /// assert!(estimate.error < 1e-10);
+    ///
+    /// let mut derivative = Derivative::new(1., 3, |x: f64| x.exp());
+    /// let estimate = derivative.richardson(Richardson::default());
+    /// assert_relative_eq!(estimate.value, 1f64.exp(), epsilon = 1e-8);
+    /// ```
+    pub fn richardson(&mut self, richardson: Richardson<T>) -> Estimate<T> {
+        let contraction = T::from_f64(1.4).unwrap();
+        let factor = contraction * contraction;
+        let safe = T::from_u8(2).unwrap();
+
+        let mut step = richardson.initial_step;
+        let mut previous: Vec<T> = alloc::vec![self.central_difference(step)];
+        let mut best = Estimate {
+            value: previous[0],
+            error: T::max_value(),
+        };
+
+        for _ in 1..richardson.max_iterations {
+            step = step / contraction;
+            let mut row = Vec::with_capacity(previous.len() + 1);
+            row.push(self.central_difference(step));
+
+            let mut scale = factor;
+            for j in 1..=previous.len() {
+                let value = (row[j - 1] * scale - previous[j - 1]) / (scale - T::one());
+                scale = scale * factor;
+
+                let error = (value - row[j - 1])
+                    .abs()
+                    .max((value - previous[j - 1]).abs());
+                if error <= best.error {
+                    best = Estimate { value, error };
+                }
+                row.push(value);
+            }
+
+            // Stop once higher order extrapolation is making things worse
+            let (last, before) = (row[row.len() - 1], previous[previous.len() - 1]);
+            if (last - before).abs() >= safe * best.error {
+                break;
+            }
+            previous = row;
+        }
+
+        best
+    }
+
+    /// Step of about `epsilon^(1 / power)` relative to `x`, adjusted to be exactly representable.
+    fn step(&self, power: usize) -> T {
+        let scale = self.x.abs().max(T::one());
+        let step = T::epsilon().powf(T::one() / T::from_usize(power).unwrap()) * scale;
+        (self.x + step) - self.x
+    }
+
+    fn central_difference(&mut self, step: T) -> T {
+        let n = self.order;
+        let half = T::from_usize(n).unwrap() / T::from_u8(2).unwrap();
+        let sum = (0..=n).fold(T::zero(), |sum, k| {
+            let offset = half - T::from_usize(k).unwrap();
+            let term = binomial::<T>(n, k) * (self.f)(self.x + offset * step);
+            if k % 2 == 0 {
+                sum + term
+            } else {
+                sum - term
+            }
+        });
+        sum / step.powi(n as i32)
+    }
+}
+
+impl<F, T> Derivative<F, T>
+where
+    F: FnMut(Complex<T>) -> Complex<T>,
+    T: Real + FromPrimitive,
+{
+    /// Approximate the first derivative with the
+    /// [complex-step method](https://en.wikipedia.org/wiki/Numerical_differentiation#Complex-variable_methods)
+    /// as `Im(f(x + ih)) / h`, which avoids subtractive cancellation so `h` can be tiny.
+    /// `f` must be analytic and real on the real axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the order isn't one.
+    ///
+    /// ```
+    /// use quickmaths::derivative::Derivative;
+    /// use num::Complex;
+    ///
+    /// let mut derivative = Derivative::new(1.5, 1, |z: Complex<f64>| z.exp() / z.sin());
+    /// let x = 1.5f64;
+    /// let exact = x.exp() * (x.sin() - x.cos()) / x.sin().powi(2);
+    /// assert!((derivative.complex_step() - exact).abs() < 1e-15);
+    /// ```
+    pub fn complex_step(&mut self) -> T {
+        assert_eq!(
+            self.order, 1,
+            "the complex-step method only approximates first derivatives"
+        );
+
+        let step = T::epsilon() * self.x.abs().max(T::one());
+        (self.f)(Complex::new(self.x, step)).im / step
+    }
+}
+
+/// Configuration for [`Derivative::richardson`].
+///
+/// The central difference step starts at `initial_step` and shrinks by a factor of 1.4
+/// for up to `max_iterations` rows of extrapolation.
+pub struct Richardson<T> {
+    pub initial_step: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for Richardson<T>
+where
+    T: FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            initial_step: T::from_f64(0.1).unwrap(),
+            max_iterations: 10,
+        }
+    }
+}
+
+/// Binomial coefficient `n` choose `k`.
+fn binomial<T>(n: usize, k: usize) -> T
+where
+    T: Real + FromPrimitive,
+{
+    (0..k.min(n - k)).fold(T::one(), |c, i| {
+        c * T::from_usize(n - i).unwrap() / T::from_usize(i + 1).unwrap()
+    })
+}
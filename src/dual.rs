@@ -0,0 +1,617 @@
+//! Forward-mode automatic differentiation with dual numbers
+
+use crate::{
+    fraction::{Ratio, Tiny},
+    Digits,
+};
+use core::{
+    cmp::Ordering,
+    num::FpCategory,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+use num::{traits::FloatConst, Float, FromPrimitive, Num, NumCast, One, ToPrimitive, Zero};
+
+/// [Dual number](https://en.wikipedia.org/wiki/Dual_number) `value + derivative ε` where `ε² = 0`.
+///
+/// Evaluating a generic function at `Dual::variable(x)` carries the exact derivative at `x`
+/// alongside the value. Comparisons only consider the value, while a dual number is only zero
+/// when both parts are.
+///
+/// ```
+/// use quickmaths::dual::Dual;
+/// use quickmaths::stats::ErrorFunction;
+/// use core::f64::consts::PI;
+/// use approx::assert_relative_eq;
+///
+/// // The series and continued fraction branches of the error function
+/// for x in [0.5, 1., 2.] {
+///     let erf = ErrorFunction::default().error(Dual::variable(x));
+///     assert_relative_eq!(erf.value, ErrorFunction::default().error(x));
+///     assert_relative_eq!(erf.derivative, 2. / PI.sqrt() * (-x * x).exp(), max_relative = 1e-12);
+/// }
+/// ```
+///
+/// Nesting dual numbers gives [hyper-dual numbers](HyperDual) for second derivatives.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual<T> {
+    pub value: T,
+    pub derivative: T,
+}
+
+/// Hyper-dual number `value + a ε₁ + b ε₂ + c ε₁ε₂`, represented as a dual number of dual numbers.
+///
+/// ```
+/// use quickmaths::dual::{Dual, HyperDual};
+/// use num::Float;
+/// use approx::assert_relative_eq;
+///
+/// let x: HyperDual<f64> = Dual::hyper_variable(2.);
+/// let y = x.powi(3) + x.sin();
+///
+/// assert_relative_eq!(y.value.value, 8. + 2f64.sin());
+/// assert_relative_eq!(y.value.derivative, 12. + 2f64.cos());
+/// assert_relative_eq!(y.derivative.derivative, 12. - 2f64.sin());
+/// ```
+pub type HyperDual<T> = Dual<Dual<T>>;
+
+impl<T> Dual<T> {
+    pub fn new(value: T, derivative: T) -> Self {
+        Self { value, derivative }
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Zero,
+{
+    /// A constant, whose derivative is zero.
+    pub fn constant(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+}
+
+impl<T> Dual<T>
+where
+    T: Zero + One,
+{
+    /// The variable being differentiated with respect to, whose derivative is one.
+    pub fn variable(value: T) -> Self {
+        Self::new(value, T::one())
+    }
+}
+
+impl<T> HyperDual<T>
+where
+    T: Zero + One,
+{
+    /// The variable being differentiated with respect to in both parts, so that the
+    /// `derivative.derivative` part of a result is the second derivative.
+    pub fn hyper_variable(value: T) -> Self {
+        Self::new(Dual::variable(value), Dual::new(T::one(), T::zero()))
+    }
+}
+
+/// Value and derivative of `f` at `x`.
+///
+/// ```
+/// use quickmaths::dual::derivative;
+/// use num::Float;
+/// use quickmaths::Integral;
+/// use approx::assert_relative_eq;
+///
+/// // d/dp of the integral of e^(px) from 0 to 1 at p = 1 is the integral of x e^x
+/// let (_, d) = derivative(1., |p| {
+///     Integral::new(0f64.into(), 1f64.into(), 100, |x| (p * x).exp()).simpson()
+/// });
+/// assert_relative_eq!(d, 1., epsilon = 1e-8);
+/// ```
+pub fn derivative<F, T>(x: T, f: F) -> (T, T)
+where
+    F: FnOnce(Dual<T>) -> Dual<T>,
+    T: Zero + One,
+{
+    let y = f(Dual::variable(x));
+    (y.value, y.derivative)
+}
+
+/// Value, first and second derivative of `f` at `x`.
+///
+/// ```
+/// use quickmaths::dual::second_derivative;
+/// use num::Float;
+/// use approx::assert_relative_eq;
+///
+/// let (y, dy, d2y) = second_derivative(0.5, |x| x.ln() * x);
+/// assert_relative_eq!(y, 0.5 * 0.5f64.ln());
+/// assert_relative_eq!(dy, 0.5f64.ln() + 1.);
+/// assert_relative_eq!(d2y, 2.);
+/// ```
+pub fn second_derivative<F, T>(x: T, f: F) -> (T, T, T)
+where
+    F: FnOnce(HyperDual<T>) -> HyperDual<T>,
+    T: Zero + One,
+{
+    let y = f(Dual::hyper_variable(x));
+    (y.value.value, y.value.derivative, y.derivative.derivative)
+}
+
+impl<T> From<T> for Dual<T>
+where
+    T: Zero,
+{
+    fn from(value: T) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl<T> From<Ratio<i32>> for Dual<T>
+where
+    T: From<Ratio<i32>> + Zero,
+{
+    fn from(ratio: Ratio<i32>) -> Self {
+        Self::constant(ratio.into())
+    }
+}
+
+impl<T> PartialOrd for Dual<T>
+where
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        )
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+        Self::new(
+            value,
+            (self.derivative - value * rhs.derivative) / rhs.value,
+        )
+    }
+}
+
+impl<T: Float> Rem for Dual<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        let quotient = (self.value / rhs.value).trunc();
+        Self::new(
+            self.value % rhs.value,
+            self.derivative - quotient * rhs.derivative,
+        )
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+macro_rules! forward_ref_binop {
+    ($($imp:ident $method:ident),*) => {
+        $(
+            impl<'a, T: Float> $imp for &'a Dual<T> {
+                type Output = Dual<T>;
+
+                fn $method(self, rhs: Self) -> Dual<T> {
+                    (*self).$method(*rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_ref_binop!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+impl<T: Float> Zero for Dual<T> {
+    fn zero() -> Self {
+        Self::constant(T::zero())
+    }
+
+    /// Both the value and the derivative must be zero, so the derivative isn't lost where zero is special.
+    fn is_zero(&self) -> bool {
+        self.value.is_zero() && self.derivative.is_zero()
+    }
+}
+
+impl<T: Float> One for Dual<T> {
+    fn one() -> Self {
+        Self::constant(T::one())
+    }
+}
+
+impl<T: Float> Num for Dual<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Self::constant)
+    }
+}
+
+impl<T: ToPrimitive> ToPrimitive for Dual<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.value.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.value.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.value.to_f64()
+    }
+}
+
+impl<T: Float> NumCast for Dual<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        T::from(n).map(Self::constant)
+    }
+}
+
+impl<T> FromPrimitive for Dual<T>
+where
+    T: FromPrimitive + Zero,
+{
+    fn from_i64(n: i64) -> Option<Self> {
+        T::from_i64(n).map(Self::constant)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        T::from_u64(n).map(Self::constant)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        T::from_f64(n).map(Self::constant)
+    }
+}
+
+macro_rules! constants {
+    ($($name:ident),*) => {
+        $(
+            fn $name() -> Self {
+                Self::constant(T::$name())
+            }
+        )*
+    };
+}
+
+impl<T> FloatConst for Dual<T>
+where
+    T: FloatConst + Zero,
+{
+    constants!(
+        E,
+        FRAC_1_PI,
+        FRAC_1_SQRT_2,
+        FRAC_2_PI,
+        FRAC_2_SQRT_PI,
+        FRAC_PI_2,
+        FRAC_PI_3,
+        FRAC_PI_4,
+        FRAC_PI_6,
+        FRAC_PI_8,
+        LN_10,
+        LN_2,
+        LOG10_E,
+        LOG2_E,
+        PI,
+        SQRT_2
+    );
+}
+
+impl<T> Tiny for Dual<T>
+where
+    T: Tiny + Float,
+{
+    fn tiny() -> Self {
+        Self::constant(T::tiny())
+    }
+
+    /// The larger magnitude of the value and the derivative, so iterations only stop once both have converged.
+    fn magnitude(self) -> Self {
+        Self::constant(self.value.magnitude().max(self.derivative.magnitude()))
+    }
+}
+
+impl<T: Digits> Digits for Dual<T> {
+    fn radix(&self) -> u32 {
+        self.value.radix()
+    }
+
+    fn digits(&self) -> u32 {
+        self.value.digits()
+    }
+}
+
+impl<T: Float> Dual<T> {
+    /// Apply a function with value `value` and derivative `slope` at `self.value` by the chain rule.
+    fn chain(self, value: T, slope: T) -> Self {
+        Self::new(value, self.derivative * slope)
+    }
+}
+
+impl<T: Float> Float for Dual<T> {
+    constants!(
+        nan,
+        infinity,
+        neg_infinity,
+        neg_zero,
+        min_value,
+        min_positive_value,
+        epsilon,
+        max_value
+    );
+
+    fn is_nan(self) -> bool {
+        self.value.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.value.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.value.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.value.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.value.classify()
+    }
+
+    fn floor(self) -> Self {
+        Self::constant(self.value.floor())
+    }
+
+    fn ceil(self) -> Self {
+        Self::constant(self.value.ceil())
+    }
+
+    fn round(self) -> Self {
+        Self::constant(self.value.round())
+    }
+
+    fn trunc(self) -> Self {
+        Self::constant(self.value.trunc())
+    }
+
+    fn fract(self) -> Self {
+        Self::new(self.value.fract(), self.derivative)
+    }
+
+    fn abs(self) -> Self {
+        self.chain(self.value.abs(), self.value.signum())
+    }
+
+    fn signum(self) -> Self {
+        Self::constant(self.value.signum())
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.value.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        let recip = self.value.recip();
+        self.chain(recip, -recip * recip)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Self::one();
+        }
+        let slope = T::from(n).unwrap() * self.value.powi(n - 1);
+        self.chain(self.value.powi(n), slope)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        // Skip terms with no derivative, which would otherwise be NaN for non-positive bases
+        let value = self.value.powf(n.value);
+        let base = if self.derivative == T::zero() {
+            T::zero()
+        } else {
+            self.derivative * n.value * self.value.powf(n.value - T::one())
+        };
+        let exponent = if n.derivative == T::zero() {
+            T::zero()
+        } else {
+            n.derivative * value * self.value.ln()
+        };
+        Self::new(value, base + exponent)
+    }
+
+    fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+        self.chain(sqrt, (sqrt + sqrt).recip())
+    }
+
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    fn exp2(self) -> Self {
+        let exp2 = self.value.exp2();
+        self.chain(exp2, exp2 * T::from(core::f64::consts::LN_2).unwrap())
+    }
+
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), self.value.recip())
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        let ln_2 = T::from(core::f64::consts::LN_2).unwrap();
+        self.chain(self.value.log2(), (self.value * ln_2).recip())
+    }
+
+    fn log10(self) -> Self {
+        let ln_10 = T::from(core::f64::consts::LN_10).unwrap();
+        self.chain(self.value.log10(), (self.value * ln_10).recip())
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other.value > self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other.value < self.value || self.value.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        if self.value > other.value {
+            self - other
+        } else {
+            Self::zero()
+        }
+    }
+
+    fn cbrt(self) -> Self {
+        let cbrt = self.value.cbrt();
+        self.chain(cbrt, (T::from(3).unwrap() * cbrt * cbrt).recip())
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        let hypot = self.value.hypot(other.value);
+        Self::new(
+            hypot,
+            (self.value * self.derivative + other.value * other.derivative) / hypot,
+        )
+    }
+
+    fn sin(self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    fn cos(self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    fn tan(self) -> Self {
+        let tan = self.value.tan();
+        self.chain(tan, T::one() + tan * tan)
+    }
+
+    fn asin(self) -> Self {
+        let slope = (T::one() - self.value * self.value).sqrt().recip();
+        self.chain(self.value.asin(), slope)
+    }
+
+    fn acos(self) -> Self {
+        let slope = -(T::one() - self.value * self.value).sqrt().recip();
+        self.chain(self.value.acos(), slope)
+    }
+
+    fn atan(self) -> Self {
+        let slope = (T::one() + self.value * self.value).recip();
+        self.chain(self.value.atan(), slope)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self.value, other.value);
+        Self::new(
+            y.atan2(x),
+            (x * self.derivative - y * other.derivative) / (x * x + y * y),
+        )
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        self.chain(self.value.exp_m1(), self.value.exp())
+    }
+
+    fn ln_1p(self) -> Self {
+        self.chain(self.value.ln_1p(), (T::one() + self.value).recip())
+    }
+
+    fn sinh(self) -> Self {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    fn cosh(self) -> Self {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+
+    fn tanh(self) -> Self {
+        let tanh = self.value.tanh();
+        self.chain(tanh, T::one() - tanh * tanh)
+    }
+
+    fn asinh(self) -> Self {
+        let slope = (self.value * self.value + T::one()).sqrt().recip();
+        self.chain(self.value.asinh(), slope)
+    }
+
+    fn acosh(self) -> Self {
+        let slope = (self.value * self.value - T::one()).sqrt().recip();
+        self.chain(self.value.acosh(), slope)
+    }
+
+    fn atanh(self) -> Self {
+        let slope = (T::one() - self.value * self.value).recip();
+        self.chain(self.value.atanh(), slope)
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.value.integer_decode()
+    }
+}
@@ -19,7 +19,7 @@ where
     let mut iter = integers.into_iter();
     iter.next()
         .map(|i| iter.fold(i, |acc, i| acc.gcd(&i)))
-        .unwrap_or_else(|| I::Item::one())
+        .unwrap_or_else(I::Item::one)
 }
 
 pub trait Factor: Clone {
@@ -60,13 +60,13 @@ pub trait Factor: Clone {
     /// assert!(3.is_prime());
     /// assert!(!4.is_prime());
     /// ```
-    fn is_prime(self) -> bool
+    fn is_prime(&self) -> bool
     where
         Self: Integer + Roots + FromPrimitive + ToPrimitive,
     {
-        if (self > Self::one()) & (self < Self::from_u8(4).unwrap()) {
+        if (*self > Self::one()) & (*self < Self::from_u8(4).unwrap()) {
             return true;
-        } else if (self < Self::from_u8(2).unwrap())
+        } else if (*self < Self::from_u8(2).unwrap())
             || (self.clone() % Self::from_u8(2).unwrap()).is_zero()
         {
             return false;
@@ -8,40 +8,28 @@ pub use gamma::{upper_gamma_fraction, UpperIncompleteGammaFraction};
 pub trait Fraction: IntoIterator<Item = Ratio<Self::Value>> + Sized {
     type Value: One + Zero + Real + Tiny + Clone;
 
+    /// Evaluate `a0 / (b0 + a1 / (b1 + ...))` for terms `ak / bk` with the modified Lentz algorithm,
+    /// until a step changes the result by a relative amount within `factor`.
     fn continued_fraction_a(self, factor: Self::Value) -> Self::Value {
         let mut fraction = self.into_iter();
         let terminator = factor.abs();
-        let mut v = fraction.next().unwrap();
+        let v = fraction.next().unwrap();
 
-        let mut f = v.numer().clone();
-        let a0 = v.denom().clone();
-
-        if f.is_zero() {
-            f = Self::Value::tiny();
-        }
+        let a0 = *v.numer();
+        let f = *v.denom();
+        let mut f = avoid_zero(f);
 
         let mut c = f;
         let mut d = Self::Value::zero();
 
-        let mut delta;
-
-        while let Some(next) = fraction.next() {
-            v = next;
-
-            d = v.numer().clone() + v.denom().clone() * d;
-            if d.is_zero() {
-                d = Self::Value::tiny();
-            }
-
-            c = v.numer().clone() + v.denom().clone() / c;
-            if c.is_zero() {
-                c = Self::Value::tiny();
-            }
+        for v in fraction {
+            d = avoid_zero(*v.denom() + *v.numer() * d).recip();
+            c = avoid_zero(*v.denom() + *v.numer() / c);
 
-            delta = c * d;
+            let delta = c * d;
             f = f * delta;
 
-            if (delta - Self::Value::one()).abs() <= terminator {
+            if (delta - Self::Value::one()).magnitude() <= terminator {
                 break;
             }
         }
@@ -50,6 +38,15 @@ pub trait Fraction: IntoIterator<Item = Ratio<Self::Value>> + Sized {
     }
 }
 
+/// Replace a zero value with a tiny one, so Lentz's algorithm can divide by it.
+fn avoid_zero<T: Real + Tiny>(x: T) -> T {
+    if x.abs() < T::tiny() {
+        x + T::tiny()
+    } else {
+        x
+    }
+}
+
 impl<I, T> Fraction for I
 where
     I: IntoIterator<Item = Ratio<T>>,
@@ -60,6 +57,14 @@ where
 
 pub trait Tiny {
     fn tiny() -> Self;
+
+    /// Size of `self` for convergence tests, which is its absolute value unless it carries more than one number.
+    fn magnitude(self) -> Self
+    where
+        Self: Real,
+    {
+        self.abs()
+    }
 }
 
 impl Tiny for f64 {
@@ -0,0 +1,181 @@
+use super::Estimate;
+use num::{traits::FloatConst, Float, FromPrimitive};
+
+/// Change of variable mapping the real line onto the range of integration.
+#[allow(clippy::enum_variant_names)]
+enum Transform<T> {
+    /// `x = tanh(π/2 sinh(t))` scaled onto `[a, b]`.
+    TanhSinh { a: T, b: T },
+    /// `x = sinh(π/2 sinh(t))` over `(-∞, ∞)`.
+    SinhSinh,
+    /// `x = origin + sign * exp(π/2 sinh(t))` over `[origin, ∞)` or `(-∞, origin]`.
+    ExpSinh { origin: T, sign: T },
+}
+
+impl<T> Transform<T>
+where
+    T: Float + FloatConst + FromPrimitive,
+{
+    /// Abscissa and weight at `t`, or `None` once the transform has left the range of `T`.
+    fn point(&self, t: T) -> Option<(T, T)> {
+        let u = T::FRAC_PI_2() * t.sinh();
+        let du = T::FRAC_PI_2() * t.cosh();
+
+        let (x, w) = match *self {
+            Self::TanhSinh { a, b } => {
+                // Measure the distance to the nearest endpoint directly as `1 - |tanh(u)|`
+                // so abscissae close to `a` or `b` don't cancel to the endpoint itself.
+                let half = (b - a) / T::from_u8(2).unwrap();
+                let complement =
+                    T::from_u8(2).unwrap() / ((T::from_u8(2).unwrap() * u.abs()).exp() + T::one());
+                let x = if t < T::zero() {
+                    a + half * complement
+                } else {
+                    b - half * complement
+                };
+                if x <= a || x >= b {
+                    return None;
+                }
+                (x, half * du / u.cosh().powi(2))
+            }
+            Self::SinhSinh => (u.sinh(), du * u.cosh()),
+            Self::ExpSinh { origin, sign } => {
+                // Near the finite endpoint the offset can be too small to move `x` away from it
+                let e = u.exp();
+                let x = origin + sign * e;
+                if x == origin {
+                    return None;
+                }
+                (x, du * e)
+            }
+        };
+
+        if x.is_finite() && w.is_finite() {
+            Some((x, w))
+        } else {
+            None
+        }
+    }
+}
+
+/// [Double exponential quadrature](https://en.wikipedia.org/wiki/Tanh-sinh_quadrature).
+///
+/// The trapezoidal rule is applied to the transformed integrand, halving the step size each level
+/// until consecutive estimates agree to within `tolerance` relative to their magnitude.
+pub struct DoubleExponential<T> {
+    pub tolerance: T,
+    pub max_levels: usize,
+}
+
+impl<T> Default for DoubleExponential<T>
+where
+    T: Float,
+{
+    fn default() -> Self {
+        Self {
+            tolerance: T::epsilon().sqrt(),
+            max_levels: 10,
+        }
+    }
+}
+
+impl<T> DoubleExponential<T>
+where
+    T: Float + FloatConst + FromPrimitive,
+{
+    pub(crate) fn integrate<F>(&self, a: T, b: T, f: &mut F) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        if a > b {
+            let estimate = self.integrate(b, a, f);
+            return Estimate {
+                value: -estimate.value,
+                error: estimate.error,
+            };
+        }
+
+        let transform = match (a.is_infinite(), b.is_infinite()) {
+            (true, true) => Transform::SinhSinh,
+            (false, true) => Transform::ExpSinh {
+                origin: a,
+                sign: T::one(),
+            },
+            (true, false) => Transform::ExpSinh {
+                origin: b,
+                sign: -T::one(),
+            },
+            (false, false) => Transform::TanhSinh { a, b },
+        };
+
+        self.trapezoid(&transform, f)
+    }
+
+    fn trapezoid<F>(&self, transform: &Transform<T>, f: &mut F) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        let two = T::from_u8(2).unwrap();
+
+        let mut h = T::one();
+        let mut sum = transform
+            .point(T::zero())
+            .map(|(x, w)| f(x) * w)
+            .unwrap_or_else(T::zero);
+        sum = sum + self.sum_direction(transform, f, h, h, sum);
+        sum = sum + self.sum_direction(transform, f, -h, -h, sum);
+
+        let mut value = sum * h;
+        let mut error = T::infinity();
+
+        for _ in 0..self.max_levels {
+            h = h / two;
+            let step = h * two;
+            let new = self.sum_direction(transform, f, h, step, sum)
+                + self.sum_direction(transform, f, -h, -step, sum);
+            sum = sum + new;
+
+            let next = sum * h;
+            error = (next - value).abs();
+            value = next;
+
+            if error <= self.tolerance * value.abs() {
+                break;
+            }
+        }
+
+        Estimate { value, error }
+    }
+
+    /// Sum the transformed integrand at `start, start + step, ...` until the terms
+    /// become negligible relative to `total` or the transform leaves the range of `T`.
+    fn sum_direction<F>(
+        &self,
+        transform: &Transform<T>,
+        f: &mut F,
+        start: T,
+        step: T,
+        total: T,
+    ) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        let mut t = start;
+        let mut sum = T::zero();
+
+        while let Some((x, w)) = transform.point(t) {
+            let term = f(x) * w;
+            if !term.is_finite() {
+                break;
+            }
+            sum = sum + term;
+
+            if t.abs() > T::one() && term.abs() <= T::epsilon() * (total + sum).abs() {
+                break;
+            }
+            t = t + step;
+        }
+
+        sum
+    }
+}
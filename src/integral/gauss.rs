@@ -0,0 +1,369 @@
+#![allow(clippy::excessive_precision)]
+
+use alloc::vec::Vec;
+use num::{
+    traits::{real::Real, FloatConst},
+    FromPrimitive,
+};
+
+/// Coefficients of the Lanczos approximation with `g = 7`.
+const LANCZOS: [f64; 9] = [
+    0.99999999999980993,
+    676.5203681218851,
+    -1259.1392167224028,
+    771.32342877765313,
+    -176.61502916214059,
+    12.507343278686905,
+    -0.13857109526572012,
+    9.9843695780195716e-6,
+    1.5056327351493116e-7,
+];
+
+/// Natural logarithm of the gamma function of a positive `x`.
+fn ln_gamma<T>(x: T) -> T
+where
+    T: Real + FloatConst + FromPrimitive,
+{
+    let half = T::from_f64(0.5).unwrap();
+    if x < half {
+        return (T::PI() / (T::PI() * x).sin().abs()).ln() - ln_gamma(T::one() - x);
+    }
+
+    let x = x - T::one();
+    let sum = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(T::from_f64(LANCZOS[0]).unwrap(), |sum, (i, &c)| {
+            sum + T::from_f64(c).unwrap() / (x + T::from_usize(i + 1).unwrap())
+        });
+    let t = x + T::from_f64(7.5).unwrap();
+
+    half * (T::PI() + T::PI()).ln() + (x + half) * t.ln() - t + sum.ln()
+}
+
+/// Domain a rule's nodes live on, and how it maps onto the bounds of an [`Integral`](super::Integral).
+enum Domain<T> {
+    /// `[-1, 1]` with weight `(1 - x)^α (1 + x)^β`, where `exponent` is `1 + α + β`.
+    Finite { exponent: T },
+    /// `[0, ∞)`.
+    SemiInfinite,
+    /// `(-∞, ∞)`.
+    Infinite,
+}
+
+/// Nodes and weights of an `n`-point [Gaussian quadrature](https://en.wikipedia.org/wiki/Gaussian_quadrature) rule,
+/// exact for polynomials of degree `2n - 1` times the rule's weight function.
+///
+/// Rules are computed once with the [Golub–Welsch algorithm](https://en.wikipedia.org/wiki/Gaussian_quadrature#The_Golub-Welsch_algorithm)
+/// and can then be applied repeatedly.
+///
+/// ```
+/// use quickmaths::integral::gauss::GaussRule;
+/// use approx::assert_relative_eq;
+///
+/// let rule = GaussRule::<f64>::legendre(5);
+/// assert_eq!(rule.nodes().len(), 5);
+/// assert_relative_eq!(rule.weights().iter().sum::<f64>(), 2., epsilon = 1e-14);
+///
+/// // Exact for polynomials of degree 9
+/// assert_relative_eq!(rule.integrate(|x| x.powi(8)), 2. / 9., epsilon = 1e-14);
+/// ```
+pub struct GaussRule<T> {
+    nodes: Vec<T>,
+    weights: Vec<T>,
+    domain: Domain<T>,
+}
+
+impl<T> GaussRule<T>
+where
+    T: Real + FloatConst + FromPrimitive,
+{
+    /// Gauss–Legendre rule on `[-1, 1]` with weight `1`.
+    pub fn legendre(n: usize) -> Self {
+        let off_diagonal = (1..n).map(|k| {
+            let k = T::from_usize(k).unwrap();
+            let four_k2 = T::from_u8(4).unwrap() * k * k;
+            k / (four_k2 - T::one()).sqrt()
+        });
+        Self::golub_welsch(
+            (0..n).map(|_| T::zero()),
+            off_diagonal,
+            T::from_u8(2).unwrap(),
+            Domain::Finite { exponent: T::one() },
+        )
+    }
+
+    /// Gauss–Jacobi rule on `[-1, 1]` with weight `(1 - x)^alpha (1 + x)^beta`, where `alpha, beta > -1`.
+    ///
+    /// ```
+    /// use quickmaths::integral::gauss::GaussRule;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // ∫ x^2 (1 - x) (1 + x)^2 dx over [-1, 1]
+    /// let rule = GaussRule::jacobi(3, 1., 2.);
+    /// assert_relative_eq!(rule.integrate(|x: f64| x * x), 4. / 15., epsilon = 1e-14);
+    /// ```
+    pub fn jacobi(n: usize, alpha: T, beta: T) -> Self {
+        let one = T::one();
+        let two = T::from_u8(2).unwrap();
+        let four = T::from_u8(4).unwrap();
+        let ab = alpha + beta;
+
+        let diagonal = (0..n).map(|k| {
+            if k == 0 {
+                (beta - alpha) / (ab + two)
+            } else {
+                let s = two * T::from_usize(k).unwrap() + ab;
+                (beta * beta - alpha * alpha) / (s * (s + two))
+            }
+        });
+        let off_diagonal = (1..n).map(|k| {
+            let b = if k == 1 {
+                four * (one + alpha) * (one + beta)
+                    / ((two + ab).powi(2) * (T::from_u8(3).unwrap() + ab))
+            } else {
+                let k = T::from_usize(k).unwrap();
+                let s = two * k + ab;
+                four * k * (k + alpha) * (k + beta) * (k + ab) / (s * s * (s + one) * (s - one))
+            };
+            b.sqrt()
+        });
+
+        let mu = (ab + one) * two.ln() + ln_gamma(alpha + one) + ln_gamma(beta + one)
+            - ln_gamma(ab + two);
+        Self::golub_welsch(
+            diagonal,
+            off_diagonal,
+            mu.exp(),
+            Domain::Finite { exponent: ab + one },
+        )
+    }
+
+    /// Gauss–Chebyshev rule of the first kind on `[-1, 1]` with weight `1 / sqrt(1 - x^2)`.
+    ///
+    /// ```
+    /// use quickmaths::integral::gauss::GaussRule;
+    /// use core::f64::consts::PI;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rule = GaussRule::chebyshev(4);
+    /// assert_relative_eq!(rule.integrate(|x: f64| x * x), PI / 2., epsilon = 1e-14);
+    /// ```
+    pub fn chebyshev(n: usize) -> Self {
+        let count = T::from_usize(n).unwrap();
+        let nodes = (0..n)
+            .rev()
+            .map(|i| {
+                let i = T::from_usize(2 * i + 1).unwrap();
+                (i * T::PI() / (count + count)).cos()
+            })
+            .collect();
+
+        Self {
+            nodes,
+            weights: (0..n).map(|_| T::PI() / count).collect(),
+            domain: Domain::Finite {
+                exponent: T::zero(),
+            },
+        }
+    }
+
+    /// Generalized Gauss–Laguerre rule on `[0, ∞)` with weight `x^alpha e^-x`, where `alpha > -1`.
+    ///
+    /// ```
+    /// use quickmaths::integral::gauss::GaussRule;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rule = GaussRule::laguerre(6, 0.);
+    /// assert_relative_eq!(rule.integrate(|x: f64| x.powi(3)), 6., epsilon = 1e-12);
+    /// ```
+    pub fn laguerre(n: usize, alpha: T) -> Self {
+        let two = T::from_u8(2).unwrap();
+        let diagonal = (0..n).map(|k| two * T::from_usize(k).unwrap() + alpha + T::one());
+        let off_diagonal = (1..n).map(|k| {
+            let k = T::from_usize(k).unwrap();
+            (k * (k + alpha)).sqrt()
+        });
+        Self::golub_welsch(
+            diagonal,
+            off_diagonal,
+            ln_gamma(alpha + T::one()).exp(),
+            Domain::SemiInfinite,
+        )
+    }
+
+    /// Gauss–Hermite rule on `(-∞, ∞)` with weight `e^(-x^2)`.
+    ///
+    /// ```
+    /// use quickmaths::integral::gauss::GaussRule;
+    /// use core::f64::consts::PI;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rule = GaussRule::hermite(10);
+    /// assert_relative_eq!(rule.integrate(|x: f64| x * x), PI.sqrt() / 2., epsilon = 1e-12);
+    /// ```
+    pub fn hermite(n: usize) -> Self {
+        let half = T::from_f64(0.5).unwrap();
+        let off_diagonal = (1..n).map(|k| (T::from_usize(k).unwrap() * half).sqrt());
+        Self::golub_welsch(
+            (0..n).map(|_| T::zero()),
+            off_diagonal,
+            T::PI().sqrt(),
+            Domain::Infinite,
+        )
+    }
+
+    /// Build a rule from the three-term recurrence of the monic orthogonal polynomials,
+    /// where `mu` is the integral of the weight function.
+    ///
+    /// The nodes are the eigenvalues of the symmetric tridiagonal Jacobi matrix and each weight
+    /// is `mu` times the squared first component of the matching normalized eigenvector.
+    /// The eigenvalues are found with the implicit QL algorithm, tracking only the first row
+    /// of the eigenvectors.
+    fn golub_welsch<D, E>(diagonal: D, off_diagonal: E, mu: T, domain: Domain<T>) -> Self
+    where
+        D: IntoIterator<Item = T>,
+        E: IntoIterator<Item = T>,
+    {
+        let mut d: Vec<T> = diagonal.into_iter().collect();
+        let n = d.len();
+        let mut e: Vec<T> = off_diagonal.into_iter().collect();
+        e.push(T::zero());
+        let mut z: Vec<T> = (0..n)
+            .map(|i| if i == 0 { T::one() } else { T::zero() })
+            .collect();
+
+        let two = T::from_u8(2).unwrap();
+        for l in 0..n {
+            for _ in 0..60 {
+                let mut m = l;
+                while m + 1 < n {
+                    let dd = d[m].abs() + d[m + 1].abs();
+                    if e[m].abs() <= T::epsilon() * dd {
+                        break;
+                    }
+                    m += 1;
+                }
+                if m == l {
+                    break;
+                }
+
+                let mut g = (d[l + 1] - d[l]) / (two * e[l]);
+                let mut r = g.hypot(T::one());
+                g = d[m] - d[l] + e[l] / (g + if g < T::zero() { -r } else { r });
+
+                let (mut s, mut c, mut p) = (T::one(), T::one(), T::zero());
+                let mut deflated = false;
+                for i in (l..m).rev() {
+                    let f = s * e[i];
+                    let b = c * e[i];
+                    r = f.hypot(g);
+                    e[i + 1] = r;
+                    if r.is_zero() {
+                        d[i + 1] = d[i + 1] - p;
+                        e[m] = T::zero();
+                        deflated = true;
+                        break;
+                    }
+                    s = f / r;
+                    c = g / r;
+                    g = d[i + 1] - p;
+                    r = (d[i] - g) * s + two * c * b;
+                    p = s * r;
+                    d[i + 1] = g + p;
+                    g = c * r - b;
+
+                    let f = z[i + 1];
+                    z[i + 1] = s * z[i] + c * f;
+                    z[i] = c * z[i] - s * f;
+                }
+                if deflated {
+                    continue;
+                }
+                d[l] = d[l] - p;
+                e[l] = g;
+                e[m] = T::zero();
+            }
+        }
+
+        let mut pairs: Vec<(T, T)> = d
+            .into_iter()
+            .zip(z.into_iter().map(|z| mu * z * z))
+            .collect();
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let (nodes, weights) = pairs.into_iter().unzip();
+
+        Self {
+            nodes,
+            weights,
+            domain,
+        }
+    }
+}
+
+impl<T> GaussRule<T> {
+    /// Nodes in ascending order.
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T] {
+        &self.weights
+    }
+}
+
+impl<T> GaussRule<T>
+where
+    T: Real + FromPrimitive,
+{
+    /// Apply the rule to `f` on the rule's own domain, with the weight function implied.
+    pub fn integrate<F>(&self, mut f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        self.nodes
+            .iter()
+            .zip(&self.weights)
+            .fold(T::zero(), |sum, (&x, &w)| sum + w * f(x))
+    }
+
+    /// Apply the rule to `f` with the rule's domain mapped onto `[start, end]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds don't match the domain: `end` must be infinite for rules on `[0, ∞)`,
+    /// and both bounds must be for rules on `(-∞, ∞)`.
+    pub(crate) fn integrate_between<F>(&self, start: T, end: T, f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        let infinite = |x: T| x.abs() > T::max_value();
+        match self.domain {
+            Domain::Finite { exponent } => {
+                // The weight is in terms of the distances to the bounds, so only the sign of the width
+                // carries over when they are reversed
+                let half = (end - start) / T::from_u8(2).unwrap();
+                let center = start + half;
+                let mut f = f;
+                half.abs().powf(exponent - T::one())
+                    * half
+                    * self.integrate(|x| f(center + half * x))
+            }
+            Domain::SemiInfinite => {
+                assert!(
+                    !infinite(start) && infinite(end) && end > start,
+                    "Gauss–Laguerre rules integrate from a finite start to infinity"
+                );
+                let mut f = f;
+                self.integrate(|x| f(start + x))
+            }
+            Domain::Infinite => {
+                assert!(
+                    infinite(start) && infinite(end) && end > start,
+                    "Gauss–Hermite rules integrate over the real line"
+                );
+                self.integrate(f)
+            }
+        }
+    }
+}
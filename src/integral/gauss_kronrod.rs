@@ -0,0 +1,223 @@
+#![allow(clippy::excessive_precision)]
+
+use super::Estimate;
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Non-negative abscissae of the symmetric 15-point Kronrod rule on `[-1, 1]`, from the outermost node inwards.
+/// Odd indices are shared with the 7-point Gauss rule.
+const K15_NODES: [f64; 8] = [
+    0.991455371120812639206854697526329,
+    0.949107912342758524526189684047851,
+    0.864864423359769072789712788640926,
+    0.741531185599394439863864773280788,
+    0.586087235467691130294144845693013,
+    0.405845151377397166906606412076961,
+    0.207784955007898467600689403773245,
+    0.000000000000000000000000000000000,
+];
+
+const K15_WEIGHTS: [f64; 8] = [
+    0.022935322010529224963732008058970,
+    0.063092092629978553290700663189204,
+    0.104790010322250183839876322541518,
+    0.140653259715525918745189590510238,
+    0.169004726639267902826583426598550,
+    0.190350578064785409913256402421014,
+    0.204432940075298892414161999234649,
+    0.209482141084727828012999174891714,
+];
+
+const G7_WEIGHTS: [f64; 4] = [
+    0.129484966168869693270611432679082,
+    0.279705391489276667901467771423780,
+    0.381830050505118944950369775488975,
+    0.417959183673469387755102040816327,
+];
+
+/// Non-negative abscissae of the symmetric 21-point Kronrod rule on `[-1, 1]`, from the outermost node inwards.
+/// Odd indices are shared with the 10-point Gauss rule.
+const K21_NODES: [f64; 11] = [
+    0.995657163025808080735527280689003,
+    0.973906528517171720077964012084452,
+    0.930157491355708226001207180059508,
+    0.865063366688984510732096688423493,
+    0.780817726586416897063717578345042,
+    0.679409568299024406234327365114874,
+    0.562757134668604683339000099272694,
+    0.433395394129247190799265943165784,
+    0.294392862701460198131126603103866,
+    0.148874338981631210884826001129720,
+    0.000000000000000000000000000000000,
+];
+
+const K21_WEIGHTS: [f64; 11] = [
+    0.011694638867371874278064396062192,
+    0.032558162307964727478818972459390,
+    0.054755896574351996031381300244580,
+    0.075039674810919952767043140916190,
+    0.093125454583697605535065465083366,
+    0.109387158802297641899210590325805,
+    0.123491976262065851077208067946401,
+    0.134709217311473325928054001771707,
+    0.142775938577060080797094273138717,
+    0.147739104901338491374841515972068,
+    0.149445554002916905664936468389821,
+];
+
+const G10_WEIGHTS: [f64; 5] = [
+    0.066671344308688137593568809893332,
+    0.149451349150580593145776339657697,
+    0.219086362515982043995534934228163,
+    0.269266719309996355091226921569469,
+    0.295524224714752870173892994651338,
+];
+
+/// Pair of nested Gauss and Kronrod rules.
+pub enum Rule {
+    /// 7-point Gauss rule embedded in a 15-point Kronrod rule.
+    G7K15,
+    /// 10-point Gauss rule embedded in a 21-point Kronrod rule.
+    G10K21,
+}
+
+impl Rule {
+    fn tables(&self) -> (&'static [f64], &'static [f64], &'static [f64]) {
+        match self {
+            Self::G7K15 => (&K15_NODES, &K15_WEIGHTS, &G7_WEIGHTS),
+            Self::G10K21 => (&K21_NODES, &K21_WEIGHTS, &G10_WEIGHTS),
+        }
+    }
+
+    /// Every node on `[-1, 1]` with its Kronrod weight and its Gauss weight,
+    /// which is zero for nodes only used by the Kronrod rule.
+    pub(crate) fn points(&self) -> Vec<(f64, f64, f64)> {
+        let (nodes, kronrod_weights, gauss_weights) = self.tables();
+        let mut points = Vec::with_capacity(2 * nodes.len());
+        for (i, (&node, &weight)) in nodes.iter().zip(kronrod_weights).enumerate() {
+            let gauss = if i % 2 == 1 { gauss_weights[i / 2] } else { 0. };
+            points.push((node, weight, gauss));
+            if node != 0. {
+                points.push((-node, weight, gauss));
+            }
+        }
+        points
+    }
+
+    /// Apply the rule once on `[a, b]`, returning the Kronrod estimate,
+    /// the difference from the embedded Gauss estimate and the integral of `|f|`.
+    fn apply<F, T>(&self, a: T, b: T, f: &mut F) -> (T, T, T)
+    where
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        let (nodes, kronrod_weights, gauss_weights) = self.tables();
+        let half = (b - a) / T::from_u8(2).unwrap();
+        let center = a + half;
+
+        let mut kronrod = T::zero();
+        let mut gauss = T::zero();
+        let mut l1 = T::zero();
+
+        for (i, (&node, &weight)) in nodes.iter().zip(kronrod_weights).enumerate() {
+            let weight = T::from_f64(weight).unwrap();
+            let (y, abs) = if node == 0. {
+                let y = f(center);
+                (y, y.abs())
+            } else {
+                let dx = half * T::from_f64(node).unwrap();
+                let (left, right) = (f(center - dx), f(center + dx));
+                (left + right, left.abs() + right.abs())
+            };
+
+            kronrod = kronrod + weight * y;
+            l1 = l1 + weight * abs;
+            if i % 2 == 1 {
+                gauss = gauss + T::from_f64(gauss_weights[i / 2]).unwrap() * y;
+            }
+        }
+
+        let kronrod = kronrod * half;
+        let gauss = gauss * half;
+        (kronrod, (kronrod - gauss).abs(), l1 * half.abs())
+    }
+}
+
+/// Adaptive [Gauss–Kronrod quadrature](https://en.wikipedia.org/wiki/Gauss%E2%80%93Kronrod_quadrature_formula).
+///
+/// Each interval is bisected until the difference between the Gauss and Kronrod estimates
+/// is within `max(absolute_tolerance, relative_tolerance * ∫|f|)` or `max_depth` is reached.
+pub struct GaussKronrod<T> {
+    pub rule: Rule,
+    pub absolute_tolerance: T,
+    pub relative_tolerance: T,
+    pub max_depth: usize,
+}
+
+impl<T> Default for GaussKronrod<T>
+where
+    T: Real + FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            rule: Rule::G7K15,
+            absolute_tolerance: T::zero(),
+            relative_tolerance: T::epsilon().sqrt(),
+            max_depth: 15,
+        }
+    }
+}
+
+impl<T> GaussKronrod<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub fn new(rule: Rule) -> Self {
+        Self {
+            rule,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn integrate<F>(&self, a: T, b: T, f: &mut F) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        let (value, error, l1) = self.rule.apply(a, b, f);
+        let tolerance = self.absolute_tolerance.max(self.relative_tolerance * l1);
+        self.recurse(a, b, f, value, error, tolerance, self.max_depth)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn recurse<F>(
+        &self,
+        a: T,
+        b: T,
+        f: &mut F,
+        value: T,
+        error: T,
+        tolerance: T,
+        depth: usize,
+    ) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        if depth == 0 || error <= tolerance || error <= self.relative_tolerance * value.abs() {
+            return Estimate { value, error };
+        }
+
+        let mid = a + (b - a) / T::from_u8(2).unwrap();
+        let tolerance = tolerance / T::from_u8(2).unwrap();
+
+        let (left_value, left_error, _) = self.rule.apply(a, mid, f);
+        let left = self.recurse(a, mid, f, left_value, left_error, tolerance, depth - 1);
+
+        let (right_value, right_error, _) = self.rule.apply(mid, b, f);
+        let right = self.recurse(mid, b, f, right_value, right_error, tolerance, depth - 1);
+
+        Estimate {
+            value: left.value + right.value,
+            error: left.error + right.error,
+        }
+    }
+}
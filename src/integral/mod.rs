@@ -1,11 +1,49 @@
 //! Integral approximations
 
+pub mod double_exponential;
+pub use double_exponential::DoubleExponential;
+
+pub mod gauss;
+pub use gauss::GaussRule;
+
+pub mod gauss_kronrod;
+pub use gauss_kronrod::GaussKronrod;
+
+pub mod monte_carlo;
+
+pub mod multi;
+pub use multi::MultiIntegral;
+
 pub mod riemann;
-use num::{traits::real::Real, Float, FromPrimitive};
-use rand::{distributions::Standard, prelude::Distribution};
 pub use riemann::RiemannSum;
 
-use self::riemann::Area;
+pub mod romberg;
+pub use romberg::Romberg;
+
+pub mod sampled;
+
+pub mod sequence;
+
+pub mod simpson;
+pub use simpson::AdaptiveSimpson;
+
+use self::{
+    monte_carlo::{Samples, Strategy},
+    riemann::Area,
+    sequence::Sequence,
+};
+use num::{
+    traits::{real::Real, FloatConst},
+    Float, FromPrimitive,
+};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+/// Approximate value of an integral along with an estimate of its absolute error.
+#[derive(Clone, Copy, Debug)]
+pub struct Estimate<T> {
+    pub value: T,
+    pub error: T,
+}
 
 pub struct Integral<F, T> {
     start: T,
@@ -41,34 +79,156 @@ impl<F, T> Integral<F, T> {
         RiemannSum::new(self.start, self.end, self.steps, self.f, area)
     }
 
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// by averaging `steps` uniformly random samples drawn from `rng`,
+    /// using the standard error of the mean as the error estimate.
+    ///
     /// ```
     /// use quickmaths::Integral;
+    /// use rand::{rngs::StdRng, SeedableRng};
     /// use approx::assert_relative_eq;
     ///
     /// // Integrate a polynomial
     /// let f = |x: f64| 3. * x.powi(2);
     /// let mut integral = Integral::new(0., 1., 100_000, f);
     ///
-    /// assert_relative_eq!(integral.monte_carlo(), 1., epsilon = 1e-2);
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let estimate = integral.monte_carlo(&mut rng);
+    /// assert_relative_eq!(estimate.value, 1., epsilon = 1e-2);
+    /// assert!(estimate.error < 1e-2);
+    ///
+    /// // The same seed reproduces the same estimate
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// assert_eq!(integral.monte_carlo(&mut rng).value, estimate.value);
     /// ```
-    pub fn monte_carlo(&mut self) -> T
+    pub fn monte_carlo<R>(&mut self, rng: &mut R) -> Estimate<T>
     where
+        R: Rng + ?Sized,
         Standard: Distribution<T>,
         F: FnMut(T) -> T,
         T: Real + FromPrimitive,
     {
         let diff = self.end - self.start;
-        let dx = diff / T::from_usize(self.steps).unwrap();
-        let sum = (1..self.steps).fold(T::zero(), |sum, _| {
-            let x = self.start + diff * rand::random();
-            sum + (self.f)(x)
-        });
+        let mut samples = Samples::new();
+        for _ in 0..self.steps {
+            let x = self.start + diff * rng.gen::<T>();
+            samples.push((self.f)(x));
+        }
+
+        samples.estimate(diff)
+    }
 
-        dx * sum
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// with `steps` evaluations of `f`, sampled from `rng` according to a variance reduction `strategy`.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::monte_carlo::{Antithetic, ControlVariate, Importance, Stratified};
+    /// use quickmaths::stats::NormalDistribution;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let mut integral = Integral::new(0., 1., 10_000, |x: f64| x.exp());
+    /// let plain = integral.monte_carlo(&mut rng);
+    ///
+    /// let stratified = integral.monte_carlo_with(Stratified { strata: 100 }, &mut rng);
+    /// assert_relative_eq!(stratified.value, 1f64.exp() - 1., epsilon = 1e-4);
+    /// assert!(stratified.error < plain.error / 10.);
+    ///
+    /// let antithetic = integral.monte_carlo_with(Antithetic, &mut rng);
+    /// assert!(antithetic.error < plain.error / 5.);
+    ///
+    /// // 1 + x approximates e^x and integrates to 3/2
+    /// let control = ControlVariate { g: |x: f64| 1. + x, integral: 1.5 };
+    /// let controlled = integral.monte_carlo_with(control, &mut rng);
+    /// assert!(controlled.error < plain.error / 5.);
+    ///
+    /// // Integrate a Gaussian over the real line by drawing from a wider normal distribution
+    /// let mut integral = Integral::new(f64::NEG_INFINITY, f64::INFINITY, 10_000, |x: f64| (-x * x).exp());
+    /// let proposal = Importance { proposal: NormalDistribution::new(0., 1.) };
+    /// let estimate = integral.monte_carlo_with(proposal, &mut rng);
+    /// assert_relative_eq!(estimate.value, core::f64::consts::PI.sqrt(), epsilon = 3. * estimate.error);
+    /// ```
+    pub fn monte_carlo_with<S, R>(&mut self, mut strategy: S, rng: &mut R) -> Estimate<T>
+    where
+        S: Strategy<T>,
+        R: Rng + ?Sized,
+        F: FnMut(T) -> T,
+        T: Copy,
+    {
+        strategy.integrate(self.start, self.end, self.steps, &mut self.f, rng)
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// by averaging `f` at the first coordinate of the next `steps` points of `sequence`.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::sequence::Sobol;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = Integral::new(0., 1., 4_096, |x: f64| 3. * x.powi(2));
+    /// assert_relative_eq!(integral.quasi_monte_carlo(&mut Sobol::new(1)), 1., epsilon = 1e-3);
+    /// ```
+    pub fn quasi_monte_carlo<S>(&mut self, sequence: &mut S) -> T
+    where
+        S: Sequence,
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        let diff = self.end - self.start;
+        let mut point = alloc::vec![0.; sequence.dimensions()];
+        let mut samples = Samples::new();
+        for _ in 0..self.steps {
+            sequence.next_point(&mut point);
+            samples.push((self.f)(self.start + diff * T::from_f64(point[0]).unwrap()));
+        }
+
+        samples.estimate(diff).value
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// with randomized quasi-Monte Carlo, averaging `replicates` independent randomizations of
+    /// `sequence` with `steps` points each. The standard error across the replicates is
+    /// used as the error estimate.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::sequence::Halton;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let mut integral = Integral::new(0., 1., 1_000, |x: f64| 3. * x.powi(2));
+    /// let estimate = integral.randomized_quasi_monte_carlo(&mut Halton::new(1), &mut rng, 16);
+    /// assert_relative_eq!(estimate.value, 1., epsilon = 1e-3);
+    /// assert!(estimate.error < 1e-3);
+    /// ```
+    pub fn randomized_quasi_monte_carlo<S, R>(
+        &mut self,
+        sequence: &mut S,
+        rng: &mut R,
+        replicates: usize,
+    ) -> Estimate<T>
+    where
+        S: Sequence,
+        R: Rng + ?Sized,
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        let mut replicate_samples = Samples::new();
+        for _ in 0..replicates {
+            sequence.randomize(rng);
+            replicate_samples.push(self.quasi_monte_carlo(sequence));
+        }
+
+        replicate_samples.estimate(T::one())
     }
 
     /// Calculate an approximation of the definite integral for function `f` from `start` to `end` with `n` steps
     /// using [Simpson's rule](https://en.wikipedia.org/wiki/Simpson%27s_rule).
+    /// An odd number of steps uses Simpson's 3/8 rule for the last three.
     ///
     /// ```
     /// use quickmaths::Integral;
@@ -95,23 +255,221 @@ impl<F, T> Integral<F, T> {
     ///
     /// assert_relative_eq!(integral.simpson(), 2.0 * PI * 5.0, epsilon = 1e-9);
     /// ```
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Cubics are integrated exactly with any number of steps
+    /// let mut integral = Integral::new(0., 2., 7, |x: f64| x.powi(3));
+    /// assert_relative_eq!(integral.simpson(), 4., epsilon = 1e-12);
+    /// ```
     pub fn simpson(&mut self) -> T
     where
         F: FnMut(T) -> T,
         T: Real + FromPrimitive,
     {
-        let mut result = (self.f)(self.start) + (self.f)(self.end);
-        let step = (self.end - self.start) / T::from_usize(self.steps).unwrap();
-
-        for i in 1..self.steps {
-            let x = self.start + step * T::from_usize(i).unwrap();
-            result = if i % 2 == 0 {
-                result + (self.f)(x) * T::from_u8(2).unwrap()
-            } else {
-                result + (self.f)(x) * T::from_u8(4).unwrap()
-            };
-        }
+        simpson::composite(self.start, self.end, self.steps, &mut self.f)
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// using Simpson's rule with `2 * steps` steps, estimating the error by comparison with
+    /// the rule on every other node.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = Integral::new(0., 1., 25, |x: f64| x.exp());
+    /// let estimate = integral.simpson_estimate();
+    ///
+    /// let exact = 1f64.exp() - 1.;
+    /// assert_relative_eq!(estimate.value, exact, epsilon = 1e-8);
+    /// assert!((estimate.value - exact).abs() < 2. * estimate.error);
+    /// ```
+    pub fn simpson_estimate(&mut self) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        simpson::composite_estimate(self.start, self.end, self.steps, &mut self.f)
+    }
 
-        result * (step / T::from_u8(3).unwrap())
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// using [adaptive Simpson's method](https://en.wikipedia.org/wiki/Adaptive_Simpson%27s_method).
+    /// `steps` is ignored.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::AdaptiveSimpson;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = Integral::new(0., 4., 0, |x: f64| x.sqrt());
+    /// let estimate = integral.adaptive_simpson(AdaptiveSimpson { tolerance: 1e-10, max_depth: 50 });
+    /// assert_relative_eq!(estimate.value, 16. / 3., epsilon = 1e-9);
+    /// ```
+    pub fn adaptive_simpson(&mut self, simpson: AdaptiveSimpson<T>) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        simpson.integrate(self.start, self.end, &mut self.f)
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// using adaptive [Gauss–Kronrod quadrature](https://en.wikipedia.org/wiki/Gauss%E2%80%93Kronrod_quadrature_formula).
+    /// `steps` is ignored; intervals are bisected until the tolerances of `quadrature` are met.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::{gauss_kronrod::Rule, GaussKronrod};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = Integral::new(0., 1., 0, |x: f64| x.exp());
+    /// let estimate = integral.gauss_kronrod(GaussKronrod::default());
+    /// assert_relative_eq!(estimate.value, 1f64.exp() - 1., epsilon = 1e-14);
+    ///
+    /// // The square root has an unbounded derivative at zero
+    /// let mut integral = Integral::new(0., 1., 0, |x: f64| x.sqrt());
+    /// let estimate = integral.gauss_kronrod(GaussKronrod::new(Rule::G10K21));
+    /// assert_relative_eq!(estimate.value, 2. / 3., epsilon = 1e-10);
+    /// assert!(estimate.error < 1e-10);
+    /// ```
+    pub fn gauss_kronrod(&mut self, quadrature: GaussKronrod<T>) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        quadrature.integrate(self.start, self.end, &mut self.f)
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// where either bound may be infinite, using
+    /// [double exponential quadrature](https://en.wikipedia.org/wiki/Tanh-sinh_quadrature).
+    /// Finite ranges use the tanh-sinh transform, semi-infinite ranges use the exp-sinh transform
+    /// and the real line uses the sinh-sinh transform. `steps` is ignored.
+    ///
+    /// `f` is never evaluated at a finite bound, so integrable singularities at the endpoints are allowed.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::DoubleExponential;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = Integral::new(0., 1., 0, |x: f64| 1. / x.sqrt());
+    /// let estimate = integral.double_exponential(DoubleExponential::default());
+    /// assert_relative_eq!(estimate.value, 2., epsilon = 1e-12);
+    ///
+    /// let mut integral = Integral::new(0., 1., 0, |x: f64| x.ln());
+    /// let estimate = integral.double_exponential(DoubleExponential::default());
+    /// assert_relative_eq!(estimate.value, -1., epsilon = 1e-12);
+    /// ```
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::DoubleExponential;
+    /// use core::f64::consts::PI;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Integrate the standard normal density over the real line
+    /// let density = |x: f64| (-x * x / 2.).exp() / (2. * PI).sqrt();
+    /// let mut integral = Integral::new(f64::NEG_INFINITY, f64::INFINITY, 0, density);
+    /// let estimate = integral.double_exponential(DoubleExponential::default());
+    /// assert_relative_eq!(estimate.value, 1., epsilon = 1e-12);
+    ///
+    /// let mut integral = Integral::new(0., f64::INFINITY, 0, |x: f64| 1. / (1. + x * x));
+    /// let estimate = integral.double_exponential(DoubleExponential::default());
+    /// assert_relative_eq!(estimate.value, PI / 2., epsilon = 1e-12);
+    ///
+    /// // Singular at the finite bound, where abscissae closer than the spacing of floats around it are skipped
+    /// let mut integral = Integral::new(1., f64::INFINITY, 0, |x: f64| 1. / ((x - 1.).sqrt() * x));
+    /// let estimate = integral.double_exponential(DoubleExponential::default());
+    /// assert_relative_eq!(estimate.value, PI, epsilon = 1e-6);
+    ///
+    /// let mut integral = Integral::new(f64::NEG_INFINITY, 1., 0, |x: f64| x.exp());
+    /// let estimate = integral.double_exponential(DoubleExponential::default());
+    /// assert_relative_eq!(estimate.value, 1f64.exp(), epsilon = 1e-12);
+    /// ```
+    pub fn double_exponential(&mut self, quadrature: DoubleExponential<T>) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        T: Float + FloatConst + FromPrimitive,
+    {
+        quadrature.integrate(self.start, self.end, &mut self.f)
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` from `start` to `end`
+    /// using [Romberg's method](https://en.wikipedia.org/wiki/Romberg%27s_method).
+    /// `steps` is ignored; the returned [`Tableau`](romberg::Tableau) holds every row of extrapolations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_rows` is less than two.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::Romberg;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = Integral::new(0., 2., 0, |x: f64| x.exp());
+    /// let tableau = integral.romberg(Romberg::default());
+    ///
+    /// let estimate = tableau.estimate();
+    /// assert_relative_eq!(estimate.value, 2f64.exp() - 1., epsilon = 1e-12);
+    /// assert!(estimate.error < 1e-8);
+    ///
+    /// // The first column holds the plain trapezoidal estimates
+    /// assert_relative_eq!(tableau.row(0)[0], 2f64.exp() + 1.);
+    /// assert_eq!(tableau.diagonal().count(), tableau.rows());
+    /// ```
+    pub fn romberg(&mut self, romberg: Romberg<T>) -> romberg::Tableau<T>
+    where
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        romberg.integrate(self.start, self.end, &mut self.f)
+    }
+
+    /// Calculate an approximation of the definite integral for function `f` times the weight
+    /// function of `rule` using [Gaussian quadrature](https://en.wikipedia.org/wiki/Gaussian_quadrature).
+    /// `steps` is ignored.
+    ///
+    /// Rules on `[-1, 1]` are mapped onto `[start, end]` with their weight in terms of the distances
+    /// `|end - x|` and `|x - start|`, Gauss–Laguerre rules are shifted to `[start, ∞)`
+    /// and Gauss–Hermite rules cover the real line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end` isn't infinity for a Gauss–Laguerre rule,
+    /// or the bounds aren't negative and positive infinity for a Gauss–Hermite rule.
+    ///
+    /// ```
+    /// use quickmaths::Integral;
+    /// use quickmaths::integral::GaussRule;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Build the rule once and reuse it
+    /// let rule = GaussRule::legendre(20);
+    /// for n in 1..5 {
+    ///     let mut integral = Integral::new(0., n as f64, 0, |x: f64| x.exp());
+    ///     assert_relative_eq!(integral.gauss(&rule), (n as f64).exp() - 1., epsilon = 1e-12);
+    /// }
+    ///
+    /// // ∫ e^-x dx over [2, ∞) with the weight e^-(x - 2)
+    /// let rule = GaussRule::laguerre(4, 0.);
+    /// let mut integral = Integral::new(2., f64::INFINITY, 0, |_| (-2f64).exp());
+    /// assert_relative_eq!(integral.gauss(&rule), (-2f64).exp(), epsilon = 1e-14);
+    ///
+    /// // ∫ sqrt(2 - x) dx from 2 to 1, where the weight of a Gauss–Jacobi rule follows the bounds
+    /// let rule = GaussRule::jacobi(4, 0., 0.5);
+    /// let mut integral = Integral::new(2., 1., 0, |_| 1.);
+    /// assert_relative_eq!(integral.gauss(&rule), -2. / 3., epsilon = 1e-12);
+    /// ```
+    pub fn gauss(&mut self, rule: &GaussRule<T>) -> T
+    where
+        F: FnMut(T) -> T,
+        T: Real + FromPrimitive,
+    {
+        rule.integrate_between(self.start, self.end, &mut self.f)
     }
 }
@@ -0,0 +1,313 @@
+//! Variance reduction strategies for Monte Carlo integration
+
+use super::Estimate;
+use num::{traits::real::Real, FromPrimitive};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+/// Running mean and variance of Monte Carlo samples using
+/// [Welford's algorithm](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm).
+pub(crate) struct Samples<T> {
+    count: usize,
+    mean: T,
+    m2: T,
+}
+
+impl<T> Samples<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            count: 0,
+            mean: T::zero(),
+            m2: T::zero(),
+        }
+    }
+
+    pub(crate) fn push(&mut self, y: T) {
+        self.count += 1;
+        let delta = y - self.mean;
+        self.mean = self.mean + delta / T::from_usize(self.count).unwrap();
+        self.m2 = self.m2 + delta * (y - self.mean);
+    }
+
+    /// The sample mean scaled by `scale`, with the standard error of the mean as the error.
+    pub(crate) fn estimate(&self, scale: T) -> Estimate<T> {
+        let error = if self.count > 1 {
+            let n = T::from_usize(self.count).unwrap();
+            (self.m2 / (n - T::one()) / n).sqrt()
+        } else {
+            T::max_value()
+        };
+
+        Estimate {
+            value: scale * self.mean,
+            error: (scale * error).abs(),
+        }
+    }
+}
+
+/// Sampling strategy for [`Integral::monte_carlo_with`](super::Integral::monte_carlo_with).
+pub trait Strategy<T> {
+    /// Estimate the integral of `f` from `start` to `end` with `steps` evaluations of `f`.
+    fn integrate<F, R>(&mut self, start: T, end: T, steps: usize, f: F, rng: &mut R) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        R: Rng + ?Sized;
+}
+
+/// [Stratified sampling](https://en.wikipedia.org/wiki/Stratified_sampling) over `strata`
+/// equal sub-intervals, each receiving an equal share of the samples up to one left over.
+///
+/// Each stratum needs two samples to estimate its variance, so fewer strata are used when there are
+/// too few samples. With fewer than two samples in total, the error is unknown and reported as `T::max_value()`.
+///
+/// # Panics
+///
+/// Panics if `strata` is zero.
+///
+/// ```
+/// use quickmaths::Integral;
+/// use quickmaths::integral::monte_carlo::Stratified;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// // Samples that don't divide evenly between the strata are still used
+/// let mut evaluations = 0;
+/// let mut integral = Integral::new(0., 1., 7, |x: f64| {
+///     evaluations += 1;
+///     x
+/// });
+/// let estimate = integral.monte_carlo_with(Stratified { strata: 3 }, &mut StdRng::seed_from_u64(1));
+/// assert!(estimate.error.is_finite());
+/// drop(integral);
+/// assert_eq!(evaluations, 7);
+/// ```
+pub struct Stratified {
+    pub strata: usize,
+}
+
+impl<T> Strategy<T> for Stratified
+where
+    T: Real + FromPrimitive,
+    Standard: Distribution<T>,
+{
+    fn integrate<F, R>(
+        &mut self,
+        start: T,
+        end: T,
+        steps: usize,
+        mut f: F,
+        rng: &mut R,
+    ) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        R: Rng + ?Sized,
+    {
+        assert!(
+            self.strata > 0,
+            "stratified sampling needs at least one stratum"
+        );
+        let strata = self.strata.min(steps / 2).max(1);
+        if steps < 2 {
+            let mut samples = Samples::new();
+            for _ in 0..steps {
+                samples.push(f(start + (end - start) * rng.gen::<T>()));
+            }
+            return samples.estimate(end - start);
+        }
+
+        let width = (end - start) / T::from_usize(strata).unwrap();
+        let (per_stratum, remainder) = (steps / strata, steps % strata);
+
+        let (value, variance) = (0..strata).fold((T::zero(), T::zero()), |(value, variance), i| {
+            let stratum_start = start + width * T::from_usize(i).unwrap();
+            let mut samples = Samples::new();
+            for _ in 0..per_stratum + usize::from(i < remainder) {
+                samples.push(f(stratum_start + width * rng.gen::<T>()));
+            }
+
+            let estimate = samples.estimate(width);
+            (
+                value + estimate.value,
+                variance + estimate.error * estimate.error,
+            )
+        });
+
+        Estimate {
+            value,
+            error: variance.sqrt(),
+        }
+    }
+}
+
+/// [Antithetic variates](https://en.wikipedia.org/wiki/Antithetic_variates), pairing each sample `x`
+/// with its reflection `start + end - x`. With an odd number of steps, the leftover evaluation
+/// is a plain sample.
+///
+/// ```
+/// use quickmaths::Integral;
+/// use quickmaths::integral::monte_carlo::Antithetic;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut evaluations = 0;
+/// let mut integral = Integral::new(0., 1., 7, |x: f64| {
+///     evaluations += 1;
+///     x
+/// });
+/// integral.monte_carlo_with(Antithetic, &mut StdRng::seed_from_u64(1));
+/// drop(integral);
+/// assert_eq!(evaluations, 7);
+/// ```
+pub struct Antithetic;
+
+impl<T> Strategy<T> for Antithetic
+where
+    T: Real + FromPrimitive,
+    Standard: Distribution<T>,
+{
+    fn integrate<F, R>(
+        &mut self,
+        start: T,
+        end: T,
+        steps: usize,
+        mut f: F,
+        rng: &mut R,
+    ) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        R: Rng + ?Sized,
+    {
+        let diff = end - start;
+        let two = T::from_u8(2).unwrap();
+        let mut samples = Samples::new();
+        for _ in 0..steps / 2 {
+            let u = diff * rng.gen::<T>();
+            samples.push((f(start + u) + f(end - u)) / two);
+        }
+        if steps % 2 == 1 {
+            samples.push(f(start + diff * rng.gen::<T>()));
+        }
+
+        samples.estimate(diff)
+    }
+}
+
+/// [Control variates](https://en.wikipedia.org/wiki/Control_variates) using a function `g`
+/// correlated with the integrand whose `integral` over the same bounds is known.
+/// The coefficient minimizing the variance is estimated from the samples.
+/// With fewer than two samples, the error is unknown and reported as `T::max_value()`.
+///
+/// ```
+/// use quickmaths::Integral;
+/// use quickmaths::integral::monte_carlo::ControlVariate;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut integral = Integral::new(0., 1., 1, |x: f64| x.exp());
+/// let control = ControlVariate { g: |x: f64| 1. + x, integral: 1.5 };
+/// let estimate = integral.monte_carlo_with(control, &mut StdRng::seed_from_u64(1));
+/// assert_eq!(estimate.error, f64::MAX);
+/// ```
+pub struct ControlVariate<G, T> {
+    pub g: G,
+    pub integral: T,
+}
+
+impl<G, T> Strategy<T> for ControlVariate<G, T>
+where
+    G: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+    Standard: Distribution<T>,
+{
+    fn integrate<F, R>(
+        &mut self,
+        start: T,
+        end: T,
+        steps: usize,
+        mut f: F,
+        rng: &mut R,
+    ) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        R: Rng + ?Sized,
+    {
+        let diff = end - start;
+        let (mut mean_f, mut mean_g) = (T::zero(), T::zero());
+        let (mut m2_f, mut m2_g, mut covariance) = (T::zero(), T::zero(), T::zero());
+        for i in 1..=steps {
+            let x = start + diff * rng.gen::<T>();
+            let (y, z) = (f(x), (self.g)(x));
+
+            let n = T::from_usize(i).unwrap();
+            let (delta_f, delta_g) = (y - mean_f, z - mean_g);
+            mean_f = mean_f + delta_f / n;
+            mean_g = mean_g + delta_g / n;
+            m2_f = m2_f + delta_f * (y - mean_f);
+            m2_g = m2_g + delta_g * (z - mean_g);
+            covariance = covariance + delta_f * (z - mean_g);
+        }
+
+        let c = if m2_g.is_zero() {
+            T::zero()
+        } else {
+            covariance / m2_g
+        };
+        let mean = mean_f - c * (mean_g - self.integral / diff);
+
+        // Like the other strategies, the error is unknown with fewer than two samples
+        let error = if steps > 1 {
+            let n = T::from_usize(steps).unwrap();
+            let residual = (m2_f - (c + c) * covariance + c * c * m2_g).max(T::zero());
+            (diff * (residual / (n - T::one()) / n).sqrt()).abs()
+        } else {
+            T::max_value()
+        };
+        Estimate {
+            value: diff * mean,
+            error,
+        }
+    }
+}
+
+/// [Importance sampling](https://en.wikipedia.org/wiki/Importance_sampling) drawing from
+/// a `proposal` distribution, which should be large wherever the integrand is.
+/// Samples outside the bounds contribute zero, so the bounds may be infinite.
+pub struct Importance<D> {
+    pub proposal: D,
+}
+
+impl<D, T> Strategy<T> for Importance<D>
+where
+    D: crate::stats::Density<Value = T> + Distribution<T>,
+    T: Real + FromPrimitive,
+{
+    fn integrate<F, R>(
+        &mut self,
+        start: T,
+        end: T,
+        steps: usize,
+        mut f: F,
+        rng: &mut R,
+    ) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+        R: Rng + ?Sized,
+    {
+        let (low, high) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let mut samples = Samples::new();
+        for _ in 0..steps {
+            let x = self.proposal.sample(rng);
+            samples.push(if x < low || x > high {
+                T::zero()
+            } else {
+                f(x) / self.proposal.pdf(&x)
+            });
+        }
+
+        let sign = if start <= end { T::one() } else { -T::one() };
+        samples.estimate(sign)
+    }
+}
@@ -0,0 +1,431 @@
+use super::{gauss_kronrod::Rule, monte_carlo::Samples, sequence::Sequence, Estimate};
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+/// Integral of a function over a hyper-rectangle.
+pub struct MultiIntegral<F, T> {
+    bounds: Vec<(T, T)>,
+    steps: usize,
+    f: F,
+}
+
+impl<F, T> MultiIntegral<F, T> {
+    /// Create an integral of `f` over the box with per-axis `(start, end)` bounds.
+    pub fn new<B>(bounds: B, steps: usize, f: F) -> Self
+    where
+        B: IntoIterator<Item = (T, T)>,
+    {
+        Self {
+            bounds: bounds.into_iter().collect(),
+            steps,
+            f,
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.bounds.len()
+    }
+}
+
+impl<F, T> MultiIntegral<F, T>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real + FromPrimitive,
+{
+    /// Calculate an approximation of the integral with the tensor product of `rule` along
+    /// every axis, using the embedded Gauss product rule as the error estimate.
+    /// `steps` is ignored.
+    ///
+    /// ```
+    /// use quickmaths::integral::{gauss_kronrod::Rule, MultiIntegral};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = MultiIntegral::new([(0., 1.), (0., 2.)], 0, |x: &[f64]| x[0] * x[1].exp());
+    /// let estimate = integral.gauss_kronrod(Rule::G7K15);
+    /// assert_relative_eq!(estimate.value, (2f64.exp() - 1.) / 2., epsilon = 1e-13);
+    /// ```
+    pub fn gauss_kronrod(&mut self, rule: Rule) -> Estimate<T> {
+        let points: Vec<(T, T, T)> = rule
+            .points()
+            .into_iter()
+            .map(|(x, k, g)| {
+                (
+                    T::from_f64(x).unwrap(),
+                    T::from_f64(k).unwrap(),
+                    T::from_f64(g).unwrap(),
+                )
+            })
+            .collect();
+        let (center, half) = self.center_and_half_widths();
+        let dimensions = self.dimensions();
+
+        let mut index = alloc::vec![0; dimensions];
+        let mut x = center.clone();
+        let (mut kronrod, mut gauss) = (T::zero(), T::zero());
+        loop {
+            let (mut kronrod_weight, mut gauss_weight) = (T::one(), T::one());
+            for (axis, &i) in index.iter().enumerate() {
+                let (node, k, g) = points[i];
+                x[axis] = center[axis] + half[axis] * node;
+                kronrod_weight = kronrod_weight * k;
+                gauss_weight = gauss_weight * g;
+            }
+
+            let y = (self.f)(&x);
+            kronrod = kronrod + kronrod_weight * y;
+            gauss = gauss + gauss_weight * y;
+
+            // Advance the multi-index like an odometer
+            let mut axis = 0;
+            while axis < dimensions {
+                index[axis] += 1;
+                if index[axis] < points.len() {
+                    break;
+                }
+                index[axis] = 0;
+                axis += 1;
+            }
+            if axis == dimensions {
+                break;
+            }
+        }
+
+        let scale = half.iter().fold(T::one(), |scale, &h| scale * h);
+        Estimate {
+            value: kronrod * scale,
+            error: ((kronrod - gauss) * scale).abs(),
+        }
+    }
+
+    /// Calculate an approximation of the integral with adaptive
+    /// [Genz–Malik cubature](https://doi.org/10.1016/0771-050X(80)90039-X).
+    /// `steps` is ignored; the limit on evaluations is set by `cubature`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two dimensions, where [`gauss_kronrod`](Self::gauss_kronrod) can be used instead.
+    ///
+    /// ```
+    /// use quickmaths::integral::{multi::GenzMalik, MultiIntegral};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let gaussian = |x: &[f64]| (-x.iter().map(|x| x * x).sum::<f64>()).exp();
+    /// let mut integral = MultiIntegral::new([(-1., 1.); 3], 0, gaussian);
+    /// let estimate = integral.genz_malik(GenzMalik::default());
+    ///
+    /// let exact = 1.4936482656248540f64.powi(3);
+    /// assert_relative_eq!(estimate.value, exact, epsilon = 1e-8);
+    /// assert!(estimate.error < 1e-6);
+    /// ```
+    pub fn genz_malik(&mut self, cubature: GenzMalik<T>) -> Estimate<T> {
+        assert!(
+            self.bounds.len() >= 2,
+            "Genz–Malik cubature requires at least two dimensions"
+        );
+        cubature.integrate(&self.bounds, &mut self.f)
+    }
+
+    /// Calculate an approximation of the integral by averaging `steps` uniformly random samples,
+    /// using the standard error of the mean as the error estimate.
+    ///
+    /// ```
+    /// use quickmaths::integral::MultiIntegral;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let mut integral = MultiIntegral::new([(0., 1.); 4], 100_000, |x: &[f64]| x.iter().sum());
+    /// let estimate = integral.monte_carlo(&mut rng);
+    /// assert_relative_eq!(estimate.value, 2., epsilon = 4. * estimate.error);
+    /// ```
+    pub fn monte_carlo<R>(&mut self, rng: &mut R) -> Estimate<T>
+    where
+        R: Rng + ?Sized,
+        Standard: Distribution<T>,
+    {
+        let mut x: Vec<T> = self.bounds.iter().map(|&(start, _)| start).collect();
+        let mut samples = Samples::new();
+        for _ in 0..self.steps {
+            for (x, &(start, end)) in x.iter_mut().zip(&self.bounds) {
+                *x = start + (end - start) * rng.gen::<T>();
+            }
+            samples.push((self.f)(&x));
+        }
+
+        samples.estimate(self.volume())
+    }
+
+    /// Calculate an approximation of the integral by averaging `f` at the next `steps` points
+    /// of `sequence`, which must have as many dimensions as the box.
+    ///
+    /// ```
+    /// use quickmaths::integral::{sequence::Sobol, MultiIntegral};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut integral = MultiIntegral::new([(0., 1.); 4], 4_096, |x: &[f64]| x.iter().product());
+    /// let value = integral.quasi_monte_carlo(&mut Sobol::new(4));
+    /// assert_relative_eq!(value, 1. / 16., epsilon = 1e-3);
+    /// ```
+    pub fn quasi_monte_carlo<S>(&mut self, sequence: &mut S) -> T
+    where
+        S: Sequence,
+    {
+        assert_eq!(sequence.dimensions(), self.dimensions());
+
+        let mut point = alloc::vec![0.; self.dimensions()];
+        let mut x: Vec<T> = self.bounds.iter().map(|&(start, _)| start).collect();
+        let mut samples = Samples::new();
+        for _ in 0..self.steps {
+            sequence.next_point(&mut point);
+            for ((x, &u), &(start, end)) in x.iter_mut().zip(&point).zip(&self.bounds) {
+                *x = start + (end - start) * T::from_f64(u).unwrap();
+            }
+            samples.push((self.f)(&x));
+        }
+
+        samples.estimate(self.volume()).value
+    }
+
+    /// Calculate an approximation of the integral with randomized quasi-Monte Carlo,
+    /// averaging `replicates` independent randomizations of `sequence` with `steps` points each.
+    /// The standard error across the replicates is used as the error estimate.
+    ///
+    /// ```
+    /// use quickmaths::integral::{sequence::Sobol, MultiIntegral};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(3);
+    /// let mut integral = MultiIntegral::new([(0., 1.); 4], 1_024, |x: &[f64]| x.iter().product());
+    /// let estimate = integral.randomized_quasi_monte_carlo(&mut Sobol::new(4), &mut rng, 16);
+    /// assert_relative_eq!(estimate.value, 1. / 16., epsilon = 1e-3);
+    /// assert!(estimate.error < 1e-3);
+    /// ```
+    pub fn randomized_quasi_monte_carlo<S, R>(
+        &mut self,
+        sequence: &mut S,
+        rng: &mut R,
+        replicates: usize,
+    ) -> Estimate<T>
+    where
+        S: Sequence,
+        R: Rng + ?Sized,
+    {
+        let mut replicate_samples = Samples::new();
+        for _ in 0..replicates {
+            sequence.randomize(rng);
+            replicate_samples.push(self.quasi_monte_carlo(sequence));
+        }
+
+        replicate_samples.estimate(T::one())
+    }
+
+    fn volume(&self) -> T {
+        self.bounds
+            .iter()
+            .fold(T::one(), |volume, &(start, end)| volume * (end - start))
+    }
+
+    fn center_and_half_widths(&self) -> (Vec<T>, Vec<T>) {
+        let two = T::from_u8(2).unwrap();
+        self.bounds
+            .iter()
+            .map(|&(start, end)| {
+                let half = (end - start) / two;
+                (start + half, half)
+            })
+            .unzip()
+    }
+}
+
+/// Adaptive cubature with the degree 7 rule of
+/// [Genz and Malik](https://doi.org/10.1016/0771-050X(80)90039-X) and its embedded degree 5 rule.
+///
+/// The region with the largest error is repeatedly split in half along the axis with the largest
+/// fourth divided difference until the total error is within
+/// `max(absolute_tolerance, relative_tolerance * |value|)` or `max_evaluations` is exceeded.
+/// Requires at least two dimensions.
+pub struct GenzMalik<T> {
+    pub absolute_tolerance: T,
+    pub relative_tolerance: T,
+    pub max_evaluations: usize,
+}
+
+impl<T> Default for GenzMalik<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            absolute_tolerance: T::zero(),
+            relative_tolerance: T::epsilon().sqrt(),
+            max_evaluations: 1_000_000,
+        }
+    }
+}
+
+struct Region<T> {
+    center: Vec<T>,
+    half: Vec<T>,
+    estimate: Estimate<T>,
+    split_axis: usize,
+}
+
+impl<T> GenzMalik<T>
+where
+    T: Real + FromPrimitive,
+{
+    fn integrate<F>(&self, bounds: &[(T, T)], f: &mut F) -> Estimate<T>
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        let two = T::from_u8(2).unwrap();
+        let (center, half) = bounds
+            .iter()
+            .map(|&(start, end)| {
+                let half = (end - start) / two;
+                (start + half, half)
+            })
+            .unzip();
+
+        let mut evaluations = 0;
+        let mut regions = alloc::vec![self.region(center, half, f, &mut evaluations)];
+
+        loop {
+            let total = regions.iter().fold(
+                Estimate {
+                    value: T::zero(),
+                    error: T::zero(),
+                },
+                |total, region| Estimate {
+                    value: total.value + region.estimate.value,
+                    error: total.error + region.estimate.error,
+                },
+            );
+
+            let tolerance = self
+                .absolute_tolerance
+                .max(self.relative_tolerance * total.value.abs());
+            if total.error <= tolerance || evaluations >= self.max_evaluations {
+                return total;
+            }
+
+            let worst = (0..regions.len())
+                .max_by(|&a, &b| {
+                    let (a, b) = (regions[a].estimate.error, regions[b].estimate.error);
+                    a.partial_cmp(&b).unwrap()
+                })
+                .unwrap();
+            let region = regions.swap_remove(worst);
+
+            let axis = region.split_axis;
+            let mut half = region.half;
+            half[axis] = half[axis] / two;
+
+            let mut left = region.center.clone();
+            left[axis] = left[axis] - half[axis];
+            let mut right = region.center;
+            right[axis] = right[axis] + half[axis];
+
+            regions.push(self.region(left, half.clone(), f, &mut evaluations));
+            regions.push(self.region(right, half, f, &mut evaluations));
+        }
+    }
+
+    /// Apply the degree 7 and degree 5 rules to the region, choosing the axis to split next.
+    fn region<F>(
+        &self,
+        center: Vec<T>,
+        half: Vec<T>,
+        f: &mut F,
+        evaluations: &mut usize,
+    ) -> Region<T>
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        let c = |x: f64| T::from_f64(x).unwrap();
+        let d = center.len();
+        let n = c(d as f64);
+        let two = c(2.);
+
+        let lambda2 = c(9. / 70.).sqrt();
+        let lambda3 = c(9. / 10.).sqrt();
+        let lambda4 = lambda3;
+        let lambda5 = c(9. / 19.).sqrt();
+
+        let mut x = center.clone();
+        let f1 = f(&x);
+
+        let (mut f2, mut f3) = (T::zero(), T::zero());
+        let (mut split_axis, mut largest) = (0, -T::one());
+        for i in 0..d {
+            let mut pair = |lambda: T| {
+                x[i] = center[i] - lambda * half[i];
+                let low = f(&x);
+                x[i] = center[i] + lambda * half[i];
+                let high = f(&x);
+                x[i] = center[i];
+                low + high
+            };
+            let sum2 = pair(lambda2);
+            let sum3 = pair(lambda3);
+            f2 = f2 + sum2;
+            f3 = f3 + sum3;
+
+            let difference = (sum2 - two * f1 - (sum3 - two * f1) / c(7.)).abs();
+            if difference > largest {
+                largest = difference;
+                split_axis = i;
+            }
+        }
+
+        let mut f4 = T::zero();
+        for i in 0..d {
+            for j in i + 1..d {
+                for (si, sj) in [(-1., -1.), (-1., 1.), (1., -1.), (1., 1.)] {
+                    x[i] = center[i] + c(si) * lambda4 * half[i];
+                    x[j] = center[j] + c(sj) * lambda4 * half[j];
+                    f4 = f4 + f(&x);
+                }
+                x[i] = center[i];
+                x[j] = center[j];
+            }
+        }
+
+        let mut f5 = T::zero();
+        for corner in 0..1usize << d {
+            for (i, x) in x.iter_mut().enumerate() {
+                let offset = lambda5 * half[i];
+                *x = if corner & (1 << i) == 0 {
+                    center[i] - offset
+                } else {
+                    center[i] + offset
+                };
+            }
+            f5 = f5 + f(&x);
+        }
+        *evaluations += 1 + 4 * d + 2 * d * (d - 1) + (1 << d);
+
+        let volume = half.iter().fold(T::one(), |v, &h| v * two * h);
+        let seven = (c(12824.) - c(9120.) * n + c(400.) * n * n) / c(19683.) * f1
+            + c(980.) / c(6561.) * f2
+            + (c(1820.) - c(400.) * n) / c(19683.) * f3
+            + c(200.) / c(19683.) * f4
+            + c(6859.) / c(19683.) / two.powi(d as i32) * f5;
+        let five = (c(729.) - c(950.) * n + c(50.) * n * n) / c(729.) * f1
+            + c(245.) / c(486.) * f2
+            + (c(265.) - c(100.) * n) / c(1458.) * f3
+            + c(25.) / c(729.) * f4;
+
+        Region {
+            center,
+            half,
+            estimate: Estimate {
+                value: volume * seven,
+                error: (volume * (seven - five)).abs(),
+            },
+            split_axis,
+        }
+    }
+}
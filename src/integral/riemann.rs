@@ -73,6 +73,31 @@ where
             shape,
         }
     }
+
+    /// Running integral yielding `(x, F(x))` at `a` and at the right edge of each slice,
+    /// where `F` is the integral from `a`. Areas are accumulated with
+    /// [Kahan summation](https://en.wikipedia.org/wiki/Kahan_summation_algorithm).
+    ///
+    /// ```
+    /// use quickmaths::integral::RiemannSum;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut antiderivative = RiemannSum::trapezoid(0., 2., 4, |x: f64| 2. * x).cumulative();
+    /// assert_eq!(antiderivative.next(), Some((0., 0.)));
+    /// assert_eq!(antiderivative.next(), Some((0.5, 0.25)));
+    ///
+    /// let (x, area) = antiderivative.last().unwrap();
+    /// assert_relative_eq!(x, 2.);
+    /// assert_relative_eq!(area, 4.);
+    /// ```
+    pub fn cumulative(self) -> Cumulative<F, T, S> {
+        Cumulative {
+            sum: self,
+            started: false,
+            total: T::zero(),
+            carry: T::zero(),
+        }
+    }
 }
 
 impl<F, T> RiemannSum<F, T, Rectangle>
@@ -119,3 +144,35 @@ where
         }
     }
 }
+
+pub struct Cumulative<F, T, S> {
+    sum: RiemannSum<F, T, S>,
+    started: bool,
+    total: T,
+    carry: T,
+}
+
+impl<F, T, S> Iterator for Cumulative<F, T, S>
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+    S: Area<T>,
+{
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some((self.sum.a, self.total));
+        }
+
+        let area = self.sum.next()?;
+        let y = area - self.carry;
+        let total = self.total + y;
+        self.carry = (total - self.total) - y;
+        self.total = total;
+
+        let x = self.sum.a + T::from_usize(self.sum.i).unwrap() * self.sum.dx;
+        Some((x, self.total))
+    }
+}
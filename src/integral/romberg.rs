@@ -0,0 +1,123 @@
+use super::Estimate;
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// [Romberg's method](https://en.wikipedia.org/wiki/Romberg%27s_method).
+///
+/// Each row of the tableau halves the step of the trapezoidal rule, reusing the previous row's
+/// function evaluations, and Richardson extrapolation is applied along the row. Iteration stops
+/// once consecutive diagonal entries agree within `tolerance` relative to their magnitude
+/// or `max_rows` rows have been built. `max_rows` must be at least two, so there is an error estimate.
+pub struct Romberg<T> {
+    pub tolerance: T,
+    pub max_rows: usize,
+}
+
+impl<T> Default for Romberg<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            tolerance: T::epsilon().sqrt(),
+            max_rows: 20,
+        }
+    }
+}
+
+impl<T> Romberg<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn integrate<F>(&self, a: T, b: T, f: &mut F) -> Tableau<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        assert!(
+            self.max_rows >= 2,
+            "Romberg integration needs at least two rows"
+        );
+
+        let two = T::from_u8(2).unwrap();
+        let four = T::from_u8(4).unwrap();
+
+        let mut h = b - a;
+        let mut tableau = Tableau {
+            entries: Vec::new(),
+            rows: 1,
+        };
+        tableau.entries.push(h / two * (f(a) + f(b)));
+
+        let mut evaluations = 1;
+        while tableau.rows < self.max_rows {
+            h = h / two;
+            let previous = tableau.rows - 1;
+
+            let sum = (0..evaluations).fold(T::zero(), |sum, k| {
+                sum + f(a + h * T::from_usize(2 * k + 1).unwrap())
+            });
+            evaluations *= 2;
+
+            let mut entry = tableau.row(previous)[0] / two + h * sum;
+            tableau.entries.push(entry);
+
+            let mut factor = T::one();
+            for m in 1..=tableau.rows {
+                factor = factor * four;
+                let above = tableau.row(previous)[m - 1];
+                entry = entry + (entry - above) / (factor - T::one());
+                tableau.entries.push(entry);
+            }
+            tableau.rows += 1;
+
+            let estimate = tableau.estimate();
+            if estimate.error <= self.tolerance * estimate.value.abs() {
+                break;
+            }
+        }
+
+        tableau
+    }
+}
+
+/// Triangular table of trapezoidal estimates and their Richardson extrapolations.
+pub struct Tableau<T> {
+    entries: Vec<T>,
+    rows: usize,
+}
+
+impl<T> Tableau<T> {
+    /// Number of rows, where row `n` used `2^n` trapezoids.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Entries of row `n`, starting from the trapezoidal estimate
+    /// followed by each order of extrapolation.
+    pub fn row(&self, n: usize) -> &[T] {
+        let start = n * (n + 1) / 2;
+        &self.entries[start..start + n + 1]
+    }
+
+    /// Iterator over the diagonal of the tableau.
+    pub fn diagonal(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.rows).map(move |n| &self.row(n)[n])
+    }
+}
+
+impl<T> Tableau<T>
+where
+    T: Real,
+{
+    /// The last diagonal entry, with the distance from its predecessor as the error estimate.
+    pub fn estimate(&self) -> Estimate<T> {
+        let last = self.rows - 1;
+        let value = self.row(last)[last];
+        let error = if last == 0 {
+            T::max_value()
+        } else {
+            (value - self.row(last - 1)[last - 1]).abs()
+        };
+        Estimate { value, error }
+    }
+}
@@ -0,0 +1,120 @@
+//! Integration of sampled `(x, y)` points on possibly non-uniform grids
+
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Integrate sampled points with the
+/// [trapezoidal rule](https://en.wikipedia.org/wiki/Trapezoidal_rule#Non-uniform_grid).
+///
+/// ```
+/// use quickmaths::integral::sampled::trapezoid;
+///
+/// let xs = [0., 1., 3., 4.];
+/// let points = xs.iter().map(|&x| (x, 2. * x));
+/// assert_eq!(trapezoid(points), 16.);
+/// ```
+pub fn trapezoid<I, T>(points: I) -> T
+where
+    I: IntoIterator<Item = (T, T)>,
+    T: Real + FromPrimitive,
+{
+    cumulative_trapezoid(points)
+        .last()
+        .map(|(_, area)| area)
+        .unwrap_or_else(T::zero)
+}
+
+/// Integrate sampled points with Simpson's rule for non-uniform grids, fitting a parabola
+/// through each pair of intervals. An odd number of intervals integrates the last one
+/// with the parabola through the last three points.
+///
+/// ```
+/// use quickmaths::integral::sampled::simpson;
+/// use approx::assert_relative_eq;
+///
+/// // Exact for quadratics on any grid
+/// let xs = [0., 0.5, 1.25, 2., 2.25, 3.];
+/// let points = xs.iter().map(|&x| (x, x * x - x));
+/// assert_relative_eq!(simpson(points), 4.5, epsilon = 1e-12);
+/// ```
+pub fn simpson<I, T>(points: I) -> T
+where
+    I: IntoIterator<Item = (T, T)>,
+    T: Real + FromPrimitive,
+{
+    let points: Vec<(T, T)> = points.into_iter().collect();
+    let n = points.len().saturating_sub(1);
+    if n < 2 {
+        return trapezoid(points);
+    }
+
+    let six = T::from_u8(6).unwrap();
+    let two = T::from_u8(2).unwrap();
+    let three = T::from_u8(3).unwrap();
+
+    let mut sum = T::zero();
+    for i in (0..n - 1).step_by(2) {
+        let ((x0, y0), (x1, y1), (x2, y2)) = (points[i], points[i + 1], points[i + 2]);
+        let (h0, h1) = (x1 - x0, x2 - x1);
+        sum = sum
+            + (h0 + h1) / six
+                * ((two - h1 / h0) * y0
+                    + (h0 + h1) * (h0 + h1) / (h0 * h1) * y1
+                    + (two - h0 / h1) * y2);
+    }
+
+    if n % 2 == 1 {
+        let ((x0, y0), (x1, y1), (x2, y2)) = (points[n - 2], points[n - 1], points[n]);
+        let (h0, h1) = (x1 - x0, x2 - x1);
+        let alpha = (two * h1 * h1 + three * h0 * h1) / (six * (h0 + h1));
+        let beta = (h1 * h1 + three * h0 * h1) / (six * h0);
+        let eta = h1 * h1 * h1 / (six * h0 * (h0 + h1));
+        sum = sum + alpha * y2 + beta * y1 - eta * y0;
+    }
+
+    sum
+}
+
+/// Running integral of sampled points with the trapezoidal rule, yielding `(x, F(x))`
+/// for each point where `F` is the integral from the first point.
+///
+/// ```
+/// use quickmaths::integral::sampled::cumulative_trapezoid;
+///
+/// let points = [(0., 0.), (1., 2.), (3., 6.), (4., 8.)];
+/// assert!(cumulative_trapezoid(points).eq([(0., 0.), (1., 1.), (3., 9.), (4., 16.)]));
+/// ```
+pub fn cumulative_trapezoid<I, T>(points: I) -> CumulativeTrapezoid<I::IntoIter, T>
+where
+    I: IntoIterator<Item = (T, T)>,
+{
+    CumulativeTrapezoid {
+        iter: points.into_iter(),
+        previous: None,
+    }
+}
+
+pub struct CumulativeTrapezoid<I, T> {
+    iter: I,
+    /// Previous point and the integral up to it.
+    previous: Option<((T, T), T)>,
+}
+
+impl<I, T> Iterator for CumulativeTrapezoid<I, T>
+where
+    I: Iterator<Item = (T, T)>,
+    T: Real + FromPrimitive,
+{
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.iter.next()?;
+        let area = match self.previous {
+            Some(((x0, y0), area)) => area + (x - x0) * (y0 + y) / T::from_u8(2).unwrap(),
+            None => T::zero(),
+        };
+        self.previous = Some(((x, y), area));
+
+        Some((x, area))
+    }
+}
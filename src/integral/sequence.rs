@@ -0,0 +1,318 @@
+//! Low-discrepancy sequences for quasi-Monte Carlo integration
+
+use crate::Factor;
+use alloc::vec::Vec;
+use rand::Rng;
+
+/// Sequence of points in the unit hypercube `[0, 1)^d`.
+pub trait Sequence {
+    fn dimensions(&self) -> usize;
+
+    /// Write the next point of the sequence into `point`, which has `dimensions()` coordinates.
+    fn next_point(&mut self, point: &mut [f64]);
+
+    /// Draw a new random scrambling of the digits from `rng` and restart the sequence,
+    /// so that each point is uniformly distributed while the set keeps its low discrepancy.
+    fn randomize<R: Rng + ?Sized>(&mut self, rng: &mut R);
+}
+
+/// The [Halton sequence](https://en.wikipedia.org/wiki/Halton_sequence),
+/// using the radical inverse in the `i`th prime base for dimension `i`.
+///
+/// ```
+/// use quickmaths::integral::sequence::{Halton, Sequence};
+///
+/// let mut halton = Halton::new(2);
+/// let mut point = [0.; 2];
+///
+/// halton.next_point(&mut point);
+/// assert_eq!(point, [1. / 2., 1. / 3.]);
+/// halton.next_point(&mut point);
+/// assert_eq!(point, [1. / 4., 2. / 3.]);
+/// ```
+pub struct Halton {
+    bases: Vec<u32>,
+    /// Random permutation of the digits at each position for each dimension, or none for the plain sequence.
+    permutations: Vec<Vec<Vec<u32>>>,
+    index: u64,
+}
+
+impl Halton {
+    pub fn new(dimensions: usize) -> Self {
+        let bases: Vec<u32> = (2u32..)
+            .filter(|&n| n.is_prime())
+            .take(dimensions)
+            .collect();
+        let permutations = bases.iter().map(|_| Vec::new()).collect();
+        Self {
+            bases,
+            permutations,
+            index: 1,
+        }
+    }
+
+    /// Create a Halton sequence with its digits scrambled by independent random permutations
+    /// at each position in every base.
+    ///
+    /// ```
+    /// use quickmaths::integral::sequence::{Halton, Sequence};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut halton = Halton::scrambled(2, &mut StdRng::seed_from_u64(3));
+    /// let mut point = [0.; 2];
+    ///
+    /// // The first 6 points still fall in different sixths of the square along both axes
+    /// let (mut halves, mut thirds) = ([0; 2], [0; 3]);
+    /// for _ in 0..6 {
+    ///     halton.next_point(&mut point);
+    ///     halves[(point[0] * 2.) as usize] += 1;
+    ///     thirds[(point[1] * 3.) as usize] += 1;
+    /// }
+    /// assert_eq!(halves, [3, 3]);
+    /// assert_eq!(thirds, [2, 2, 2]);
+    /// ```
+    pub fn scrambled<R: Rng + ?Sized>(dimensions: usize, rng: &mut R) -> Self {
+        let mut halton = Self::new(dimensions);
+        halton.randomize(rng);
+        halton
+    }
+}
+
+impl Sequence for Halton {
+    fn dimensions(&self) -> usize {
+        self.bases.len()
+    }
+
+    fn next_point(&mut self, point: &mut [f64]) {
+        for ((x, &base), permutations) in point.iter_mut().zip(&self.bases).zip(&self.permutations)
+        {
+            let inverse_base = 1. / base as f64;
+            let mut n = self.index;
+            let mut scale = inverse_base;
+            *x = 0.;
+
+            // Permuted zeros don't terminate with the index, so continue through every permutation
+            let mut k = 0;
+            while n > 0 || k < permutations.len() {
+                let digit = (n % base as u64) as usize;
+                let digit = permutations.get(k).map_or(digit as u32, |p| p[digit]);
+                *x += digit as f64 * scale;
+                n /= base as u64;
+                scale *= inverse_base;
+                k += 1;
+            }
+        }
+        self.index += 1;
+    }
+
+    fn randomize<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for (permutations, &base) in self.permutations.iter_mut().zip(&self.bases) {
+            // Enough digits to reach the precision of `f64`
+            let mut digits = 0;
+            let mut scale = 1u64;
+            while scale < 1 << f64::MANTISSA_DIGITS {
+                scale = scale.saturating_mul(base as u64);
+                digits += 1;
+            }
+            *permutations = (0..digits)
+                .map(|_| {
+                    // Fisher–Yates shuffle
+                    let mut permutation: Vec<u32> = (0..base).collect();
+                    for i in (1..permutation.len()).rev() {
+                        permutation.swap(i, rng.gen_range(0..=i));
+                    }
+                    permutation
+                })
+                .collect();
+        }
+        self.index = 1;
+    }
+}
+
+/// Degree, coefficients and initial direction numbers of the primitive polynomial for each
+/// dimension after the first, from the `new-joe-kuo-6.21201` table of
+/// [Joe and Kuo](https://web.maths.unsw.edu.au/~fkuo/sobol/).
+const DIRECTIONS: [(u32, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+const BITS: usize = 32;
+
+/// The [Sobol sequence](https://en.wikipedia.org/wiki/Sobol_sequence) in up to 21 dimensions,
+/// generated in Gray code order and skipping the initial point at the origin.
+/// The 32-bit direction numbers give at most `2^32 - 1` points, and generating more panics.
+///
+/// ```
+/// use quickmaths::integral::sequence::{Sequence, Sobol};
+///
+/// let mut sobol = Sobol::new(2);
+/// let mut point = [0.; 2];
+///
+/// sobol.next_point(&mut point);
+/// assert_eq!(point, [0.5, 0.5]);
+/// sobol.next_point(&mut point);
+/// assert_eq!(point, [0.75, 0.25]);
+/// sobol.next_point(&mut point);
+/// assert_eq!(point, [0.25, 0.75]);
+/// ```
+pub struct Sobol {
+    directions: Vec<[u32; BITS]>,
+    state: Vec<u32>,
+    /// Random digital shift applied to each coordinate after scrambling the direction numbers.
+    shift: Vec<u32>,
+    index: u32,
+}
+
+impl Sobol {
+    /// Create a Sobol sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dimensions` is greater than 21.
+    pub fn new(dimensions: usize) -> Self {
+        assert!(
+            dimensions <= DIRECTIONS.len() + 1,
+            "Sobol sequences are limited to {} dimensions",
+            DIRECTIONS.len() + 1
+        );
+
+        let mut directions = Vec::with_capacity(dimensions);
+        if dimensions > 0 {
+            let mut v = [0; BITS];
+            for (i, v) in v.iter_mut().enumerate() {
+                *v = 1 << (BITS - 1 - i);
+            }
+            directions.push(v);
+        }
+
+        for &(degree, coefficients, initial) in DIRECTIONS.iter().take(dimensions.saturating_sub(1))
+        {
+            let s = degree as usize;
+            let mut v = [0; BITS];
+            for (i, &m) in initial.iter().enumerate() {
+                v[i] = m << (BITS - 1 - i);
+            }
+            for i in s..BITS {
+                v[i] = v[i - s] ^ (v[i - s] >> s);
+                for k in 1..s {
+                    if (coefficients >> (s - 1 - k)) & 1 == 1 {
+                        v[i] ^= v[i - k];
+                    }
+                }
+            }
+            directions.push(v);
+        }
+
+        Self {
+            directions,
+            state: alloc::vec![0; dimensions],
+            shift: alloc::vec![0; dimensions],
+            index: 0,
+        }
+    }
+
+    /// Create a Sobol sequence scrambled by a random linear matrix and a random digital shift
+    /// in every dimension.
+    ///
+    /// ```
+    /// use quickmaths::integral::sequence::{Sequence, Sobol};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let mut sobol = Sobol::scrambled(2, &mut StdRng::seed_from_u64(3));
+    /// let mut point = [0.; 2];
+    ///
+    /// // Together with the skipped first point, the first 15 points still fall
+    /// // in different sixteenths of the unit interval along each axis
+    /// let mut strata = [[0; 16]; 2];
+    /// for _ in 0..15 {
+    ///     sobol.next_point(&mut point);
+    ///     for (strata, &x) in strata.iter_mut().zip(&point) {
+    ///         strata[(x * 16.) as usize] += 1;
+    ///     }
+    /// }
+    /// for strata in strata {
+    ///     assert_eq!(strata.iter().filter(|&&n| n == 1).count(), 15);
+    /// }
+    /// ```
+    pub fn scrambled<R: Rng + ?Sized>(dimensions: usize, rng: &mut R) -> Self {
+        let mut sobol = Self::new(dimensions);
+        sobol.randomize(rng);
+        sobol
+    }
+}
+
+impl Sequence for Sobol {
+    fn dimensions(&self) -> usize {
+        self.directions.len()
+    }
+
+    fn next_point(&mut self, point: &mut [f64]) {
+        assert!(
+            self.index < u32::MAX,
+            "Sobol sequences are limited to 2^32 - 1 points"
+        );
+
+        // Flip the direction number of the lowest zero bit of the index
+        let bit = self.index.trailing_ones() as usize;
+        self.index += 1;
+
+        for (((x, state), directions), shift) in point
+            .iter_mut()
+            .zip(&mut self.state)
+            .zip(&self.directions)
+            .zip(&self.shift)
+        {
+            *state ^= directions[bit];
+            *x = (*state ^ shift) as f64 / (1u64 << BITS) as f64;
+        }
+    }
+
+    fn randomize<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let unscrambled = Self::new(self.dimensions()).directions;
+        for ((directions, unscrambled), shift) in self
+            .directions
+            .iter_mut()
+            .zip(unscrambled)
+            .zip(&mut self.shift)
+        {
+            // Random lower triangular matrix with a unit diagonal, where row `r` gives
+            // output bit `r` counting from the most significant
+            let mut matrix = [0u32; BITS];
+            for (r, row) in matrix.iter_mut().enumerate() {
+                let below = (!0u32).checked_shl((BITS - r) as u32).unwrap_or(0);
+                *row = (rng.gen::<u32>() & below) | (1 << (BITS - 1 - r));
+            }
+
+            for (direction, unscrambled) in directions.iter_mut().zip(unscrambled) {
+                *direction = 0;
+                for (r, row) in matrix.iter().enumerate() {
+                    *direction |= ((row & unscrambled).count_ones() & 1) << (BITS - 1 - r);
+                }
+            }
+            *shift = rng.gen();
+        }
+        for state in &mut self.state {
+            *state = 0;
+        }
+        self.index = 0;
+    }
+}
@@ -0,0 +1,197 @@
+use super::Estimate;
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Weight of node `i` of a composite rule over `n` intervals, in units of the step size.
+///
+/// Even `n` uses Simpson's 1/3 rule throughout. Odd `n` uses Simpson's 1/3 rule on the first
+/// `n - 3` intervals and [Simpson's 3/8 rule](https://en.wikipedia.org/wiki/Simpson%27s_rule#Simpson's_3/8_rule)
+/// on the last three, and a single interval falls back to the trapezoidal rule.
+fn weight<T>(i: usize, n: usize) -> T
+where
+    T: Real + FromPrimitive,
+{
+    if n == 1 {
+        return T::from_f64(0.5).unwrap();
+    }
+
+    let m = if n % 2 == 1 { n - 3 } else { n };
+    let mut weight = T::zero();
+    if m > 0 && i <= m {
+        let c = if i == 0 || i == m {
+            1
+        } else if i % 2 == 1 {
+            4
+        } else {
+            2
+        };
+        weight = weight + T::from_u8(c).unwrap() / T::from_u8(3).unwrap();
+    }
+    if m < n && i >= m {
+        let c = if i == m || i == n { 1 } else { 3 };
+        weight = weight + T::from_u8(3 * c).unwrap() / T::from_u8(8).unwrap();
+    }
+    weight
+}
+
+/// Composite Simpson's rule over `n` intervals of `[a, b]` for any `n > 0`.
+pub(crate) fn composite<F, T>(a: T, b: T, n: usize, mut f: F) -> T
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+{
+    let step = (b - a) / T::from_usize(n).unwrap();
+    let sum = (0..=n).fold(T::zero(), |sum, i| {
+        let x = if i == n {
+            b
+        } else {
+            a + step * T::from_usize(i).unwrap()
+        };
+        sum + weight::<T>(i, n) * f(x)
+    });
+
+    sum * step
+}
+
+/// Composite Simpson's rule over `2n` intervals, with the error estimated by
+/// Richardson extrapolation against the rule over `n` intervals sharing every other node.
+pub(crate) fn composite_estimate<F, T>(a: T, b: T, n: usize, mut f: F) -> Estimate<T>
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+{
+    let fine = 2 * n;
+    let step = (b - a) / T::from_usize(fine).unwrap();
+    let ys: Vec<T> = (0..=fine)
+        .map(|i| {
+            f(if i == fine {
+                b
+            } else {
+                a + step * T::from_usize(i).unwrap()
+            })
+        })
+        .collect();
+
+    let fine_sum = ys
+        .iter()
+        .enumerate()
+        .fold(T::zero(), |sum, (i, &y)| sum + weight::<T>(i, fine) * y);
+    let coarse_sum = ys
+        .iter()
+        .step_by(2)
+        .enumerate()
+        .fold(T::zero(), |sum, (i, &y)| sum + weight::<T>(i, n) * y);
+
+    let value = fine_sum * step;
+    let coarse = coarse_sum * (step + step);
+    Estimate {
+        value,
+        error: (value - coarse).abs() / T::from_u8(15).unwrap(),
+    }
+}
+
+/// Adaptive [Simpson's method](https://en.wikipedia.org/wiki/Adaptive_Simpson%27s_method).
+///
+/// Each interval is bisected until Simpson's rule on the halves agrees with the rule on the whole
+/// interval within `15 * tolerance`, with the tolerance halved for each half, or `max_depth` is reached.
+pub struct AdaptiveSimpson<T> {
+    pub tolerance: T,
+    pub max_depth: usize,
+}
+
+impl<T> Default for AdaptiveSimpson<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            tolerance: T::epsilon().sqrt(),
+            max_depth: 50,
+        }
+    }
+}
+
+impl<T> AdaptiveSimpson<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn integrate<F>(&self, a: T, b: T, f: &mut F) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        let (fa, fb) = (f(a), f(b));
+        let (m, fm, whole) = Self::simpson(a, fa, b, fb, f);
+        self.recurse(
+            a,
+            fa,
+            b,
+            fb,
+            m,
+            fm,
+            whole,
+            self.tolerance,
+            self.max_depth,
+            f,
+        )
+    }
+
+    /// Simpson's rule on `[a, b]`, returning the midpoint and its value along with the estimate.
+    fn simpson<F>(a: T, fa: T, b: T, fb: T, f: &mut F) -> (T, T, T)
+    where
+        F: FnMut(T) -> T,
+    {
+        let m = a + (b - a) / T::from_u8(2).unwrap();
+        let fm = f(m);
+        let estimate = (b - a) / T::from_u8(6).unwrap() * (fa + T::from_u8(4).unwrap() * fm + fb);
+        (m, fm, estimate)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn recurse<F>(
+        &self,
+        a: T,
+        fa: T,
+        b: T,
+        fb: T,
+        m: T,
+        fm: T,
+        whole: T,
+        tolerance: T,
+        depth: usize,
+        f: &mut F,
+    ) -> Estimate<T>
+    where
+        F: FnMut(T) -> T,
+    {
+        let (left_m, left_fm, left) = Self::simpson(a, fa, m, fm, f);
+        let (right_m, right_fm, right) = Self::simpson(m, fm, b, fb, f);
+        let difference = left + right - whole;
+        let fifteen = T::from_u8(15).unwrap();
+
+        if depth == 0 || difference.abs() <= fifteen * tolerance {
+            return Estimate {
+                value: left + right + difference / fifteen,
+                error: difference.abs() / fifteen,
+            };
+        }
+
+        let tolerance = tolerance / T::from_u8(2).unwrap();
+        let left = self.recurse(a, fa, m, fm, left_m, left_fm, left, tolerance, depth - 1, f);
+        let right = self.recurse(
+            m,
+            fm,
+            b,
+            fb,
+            right_m,
+            right_fm,
+            right,
+            tolerance,
+            depth - 1,
+            f,
+        );
+        Estimate {
+            value: left.value + right.value,
+            error: left.error + right.error,
+        }
+    }
+}
@@ -2,9 +2,15 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+pub mod derivative;
+
 mod digits;
 pub use digits::Digits;
 
+pub mod dual;
+
 pub mod factor;
 pub use factor::Factor;
 
@@ -13,6 +19,14 @@ pub mod fraction;
 pub mod integral;
 pub use integral::Integral;
 
+mod linalg;
+
+pub mod ode;
+
+pub mod optimize;
+
+pub mod roots;
+
 pub mod series;
 pub mod stats;
 
@@ -0,0 +1,86 @@
+//! Dense linear algebra on row-major square matrices
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num::traits::real::Real;
+
+/// LU decomposition with partial pivoting.
+pub(crate) struct Lu<T> {
+    /// Unit lower triangular factor below the diagonal and upper triangular factor on and above it.
+    matrix: Vec<T>,
+    pivots: Vec<usize>,
+    n: usize,
+}
+
+impl<T: Real> Lu<T> {
+    /// Decompose the `n` by `n` `matrix`, returning `None` if it is singular.
+    pub(crate) fn new(mut matrix: Vec<T>, n: usize) -> Option<Self> {
+        let mut pivots: Vec<usize> = (0..n).collect();
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    matrix[a * n + k]
+                        .abs()
+                        .partial_cmp(&matrix[b * n + k].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap();
+            // Also rejects NaN pivots
+            if matrix[pivot * n + k].abs().partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+                return None;
+            }
+            if pivot != k {
+                for j in 0..n {
+                    matrix.swap(k * n + j, pivot * n + j);
+                }
+                pivots.swap(k, pivot);
+            }
+
+            let diagonal = matrix[k * n + k];
+            for i in k + 1..n {
+                let factor = matrix[i * n + k] / diagonal;
+                matrix[i * n + k] = factor;
+                for j in k + 1..n {
+                    matrix[i * n + j] = matrix[i * n + j] - factor * matrix[k * n + j];
+                }
+            }
+        }
+
+        Some(Self { matrix, pivots, n })
+    }
+
+    /// Solve `A x = b` in place.
+    pub(crate) fn solve(&self, b: &mut [T]) {
+        let n = self.n;
+        let mut x: Vec<T> = self.pivots.iter().map(|&p| b[p]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.matrix[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] = x[i] - self.matrix[i * n + j] * x[j];
+            }
+            x[i] = x[i] / self.matrix[i * n + i];
+        }
+        b.copy_from_slice(&x);
+    }
+
+    /// Inverse of the decomposed matrix.
+    pub(crate) fn inverse(&self) -> Vec<T> {
+        let n = self.n;
+        let mut inverse = alloc::vec![T::zero(); n * n];
+        let mut column = alloc::vec![T::zero(); n];
+        for j in 0..n {
+            for (i, c) in column.iter_mut().enumerate() {
+                *c = if i == j { T::one() } else { T::zero() };
+            }
+            self.solve(&mut column);
+            for i in 0..n {
+                inverse[i * n + j] = column[i];
+            }
+        }
+        inverse
+    }
+}
@@ -0,0 +1,269 @@
+#![allow(clippy::excessive_precision)]
+
+use super::{error_norm, initial_step, Solution, Status};
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Nodes of the Dormand–Prince tableau.
+const C: [f64; 7] = [0., 1. / 5., 3. / 10., 4. / 5., 8. / 9., 1., 1.];
+
+/// Coefficients of the Dormand–Prince tableau, where the last row is the fifth order solution.
+const A: [&[f64]; 7] = [
+    &[],
+    &[1. / 5.],
+    &[3. / 40., 9. / 40.],
+    &[44. / 45., -56. / 15., 32. / 9.],
+    &[
+        19372. / 6561.,
+        -25360. / 2187.,
+        64448. / 6561.,
+        -212. / 729.,
+    ],
+    &[
+        9017. / 3168.,
+        -355. / 33.,
+        46732. / 5247.,
+        49. / 176.,
+        -5103. / 18656.,
+    ],
+    &[
+        35. / 384.,
+        0.,
+        500. / 1113.,
+        125. / 192.,
+        -2187. / 6784.,
+        11. / 84.,
+    ],
+];
+
+/// Difference between the fifth and fourth order weights.
+const E: [f64; 7] = [
+    71. / 57600.,
+    0.,
+    -71. / 16695.,
+    71. / 1920.,
+    -17253. / 339200.,
+    22. / 525.,
+    -1. / 40.,
+];
+
+/// Weights of the continuous extension by Hairer, Nørsett and Wanner.
+const D: [f64; 7] = [
+    -12715105075. / 11282082432.,
+    0.,
+    87487479700. / 32700410799.,
+    -10690763975. / 1880347072.,
+    701980252875. / 199316789632.,
+    -1453857185. / 822651844.,
+    69997945. / 29380423.,
+];
+
+/// Configuration for [`InitialValue::dormand_prince`](super::InitialValue::dormand_prince).
+///
+/// Steps are accepted when the root mean square of the estimated local error, scaled by
+/// `absolute_tolerance + relative_tolerance * |y|` for each component, is at most one.
+/// The first step is chosen automatically unless `initial_step` is given.
+pub struct DormandPrince<T> {
+    pub absolute_tolerance: T,
+    pub relative_tolerance: T,
+    pub initial_step: Option<T>,
+    pub max_steps: usize,
+}
+
+impl<T> Default for DormandPrince<T>
+where
+    T: FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            absolute_tolerance: T::from_f64(1e-10).unwrap(),
+            relative_tolerance: T::from_f64(1e-8).unwrap(),
+            initial_step: None,
+            max_steps: 100_000,
+        }
+    }
+}
+
+impl<T> DormandPrince<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn solve<F>(&self, start: T, initial: &[T], end: T, f: &mut F) -> DenseSolution<T>
+    where
+        F: FnMut(T, &[T], &mut [T]),
+    {
+        let n = initial.len();
+        let c: Vec<T> = C.iter().map(|&c| T::from_f64(c).unwrap()).collect();
+        let a: Vec<Vec<T>> = A
+            .iter()
+            .map(|row| row.iter().map(|&a| T::from_f64(a).unwrap()).collect())
+            .collect();
+        let e: Vec<T> = E.iter().map(|&e| T::from_f64(e).unwrap()).collect();
+        let d: Vec<T> = D.iter().map(|&d| T::from_f64(d).unwrap()).collect();
+
+        let safety = T::from_f64(0.9).unwrap();
+        let (min_factor, max_factor) = (T::from_f64(0.2).unwrap(), T::from_u8(10).unwrap());
+        let exponent = T::from_f64(0.2).unwrap();
+
+        let mut dense = DenseSolution {
+            solution: Solution::new(start, initial),
+            coefficients: Vec::new(),
+        };
+        let direction = if end < start { -T::one() } else { T::one() };
+
+        let mut t = start;
+        let mut y = initial.to_vec();
+        let mut k = alloc::vec![alloc::vec![T::zero(); n]; 7];
+        f(t, &y, &mut k[0]);
+        let mut evaluations = 1;
+
+        let mut h = match self.initial_step {
+            Some(h) => h.abs(),
+            None => {
+                evaluations += 1;
+                initial_step(
+                    f,
+                    t,
+                    &y,
+                    &k[0],
+                    direction,
+                    5,
+                    self.absolute_tolerance,
+                    self.relative_tolerance,
+                )
+            }
+        };
+
+        let mut stage = alloc::vec![T::zero(); n];
+        let mut error = alloc::vec![T::zero(); n];
+        let mut steps = 0;
+        while (end - t) * direction > T::zero() {
+            if steps == self.max_steps {
+                dense.solution.status = Status::MaxSteps;
+                break;
+            }
+            if h <= T::from_u8(16).unwrap() * T::epsilon() * t.abs() {
+                dense.solution.status = Status::StepSizeUnderflow;
+                break;
+            }
+            steps += 1;
+
+            // Don't step past the end
+            let last = h >= (end - t).abs();
+            let step = if last { end - t } else { direction * h };
+
+            for i in 1..7 {
+                for (j, s) in stage.iter_mut().enumerate() {
+                    *s = y[j]
+                        + step
+                            * a[i]
+                                .iter()
+                                .zip(&k)
+                                .fold(T::zero(), |sum, (&a, k)| sum + a * k[j]);
+                }
+                f(t + c[i] * step, &stage, &mut k[i]);
+            }
+            evaluations += 6;
+
+            // The last stage is evaluated at the fifth order solution
+            for (j, err) in error.iter_mut().enumerate() {
+                *err = step
+                    * e.iter()
+                        .zip(&k)
+                        .fold(T::zero(), |sum, (&e, k)| sum + e * k[j]);
+            }
+            let norm = error_norm(
+                &error,
+                &y,
+                &stage,
+                self.absolute_tolerance,
+                self.relative_tolerance,
+            );
+
+            let factor = if norm.is_zero() {
+                max_factor
+            } else {
+                (safety * norm.powf(-exponent))
+                    .max(min_factor)
+                    .min(max_factor)
+            };
+
+            if norm <= T::one() {
+                // Coefficients of the continuous extension over the step
+                for j in 0..n {
+                    let difference = stage[j] - y[j];
+                    let slope = step * k[0][j] - difference;
+                    dense.coefficients.extend_from_slice(&[
+                        y[j],
+                        difference,
+                        slope,
+                        difference - step * k[6][j] - slope,
+                        step * d
+                            .iter()
+                            .zip(&k)
+                            .fold(T::zero(), |sum, (&d, k)| sum + d * k[j]),
+                    ]);
+                }
+
+                t = if last { end } else { t + step };
+                y.copy_from_slice(&stage);
+                dense.solution.push(t, &y);
+                // First same as last
+                k.swap(0, 6);
+                h = h * factor;
+            } else {
+                h = h * factor.min(T::one());
+            }
+        }
+
+        dense.solution.evaluations = evaluations;
+        dense
+    }
+}
+
+/// Solution with a fourth order interpolant between the steps.
+#[derive(Clone, Debug)]
+pub struct DenseSolution<T> {
+    pub solution: Solution<T>,
+    /// Five coefficients for each component of each step.
+    coefficients: Vec<T>,
+}
+
+impl<T> DenseSolution<T>
+where
+    T: Real,
+{
+    /// Write the interpolated state at time `t` into `y`.
+    /// Times outside the solution are extrapolated from the first or last step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the solution has no steps.
+    pub fn interpolate(&self, t: T, y: &mut [T]) {
+        let times = &self.solution.times;
+        assert!(times.len() > 1, "the solution has no steps to interpolate");
+
+        let forward = times[1] > times[0];
+        let step = times[1..]
+            .iter()
+            .position(|&end| if forward { t <= end } else { t >= end })
+            .unwrap_or(times.len() - 2);
+
+        let (start, end) = (times[step], times[step + 1]);
+        let theta = (t - start) / (end - start);
+        let complement = T::one() - theta;
+
+        let n = self.solution.dimensions;
+        let coefficients = &self.coefficients[step * 5 * n..(step + 1) * 5 * n];
+        for (y, r) in y.iter_mut().zip(coefficients.chunks(5)) {
+            *y = r[0] + theta * (r[1] + complement * (r[2] + theta * (r[3] + complement * r[4])));
+        }
+    }
+
+    /// Interpolated state at time `t`.
+    pub fn at(&self, t: T) -> Vec<T> {
+        let mut y = alloc::vec![T::zero(); self.solution.dimensions];
+        self.interpolate(t, &mut y);
+        y
+    }
+}
@@ -0,0 +1,262 @@
+//! Initial value problems for ordinary differential equations
+
+mod dormand_prince;
+pub use dormand_prince::{DenseSolution, DormandPrince};
+
+mod rosenbrock;
+pub use rosenbrock::Rosenbrock;
+
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Initial value problem `y' = f(t, y)` with `y(start) = initial`, where `f(t, y, dy)` writes
+/// the derivative of the state `y` at time `t` into `dy`.
+pub struct InitialValue<F, T> {
+    start: T,
+    initial: Vec<T>,
+    f: F,
+}
+
+impl<F, T> InitialValue<F, T> {
+    pub fn new<Y>(start: T, initial: Y, f: F) -> Self
+    where
+        Y: IntoIterator<Item = T>,
+    {
+        Self {
+            start,
+            initial: initial.into_iter().collect(),
+            f,
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.initial.len()
+    }
+}
+
+impl<F, T> InitialValue<F, T>
+where
+    F: FnMut(T, &[T], &mut [T]),
+    T: Real + FromPrimitive,
+{
+    /// Solve up to `end` with the classic fourth order
+    /// [Runge–Kutta method](https://en.wikipedia.org/wiki/Runge%E2%80%93Kutta_methods) in `steps` equal steps.
+    ///
+    /// ```
+    /// use quickmaths::ode::InitialValue;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Harmonic oscillator
+    /// let mut problem = InitialValue::new(0., [1., 0.], |_t, y: &[f64], dy: &mut [f64]| {
+    ///     dy[0] = y[1];
+    ///     dy[1] = -y[0];
+    /// });
+    /// let solution = problem.rk4(1., 100);
+    ///
+    /// let (t, y) = solution.last();
+    /// assert_eq!(t, 1.);
+    /// assert_relative_eq!(y[0], 1f64.cos(), epsilon = 1e-9);
+    /// assert_relative_eq!(y[1], -1f64.sin(), epsilon = 1e-9);
+    /// ```
+    pub fn rk4(&mut self, end: T, steps: usize) -> Solution<T> {
+        let n = self.dimensions();
+        let h = (end - self.start) / T::from_usize(steps).unwrap();
+        let two = T::from_u8(2).unwrap();
+        let six = T::from_u8(6).unwrap();
+
+        let mut solution = Solution::new(self.start, &self.initial);
+        let mut y = self.initial.clone();
+        let mut stage = alloc::vec![T::zero(); n];
+        let mut k = alloc::vec![alloc::vec![T::zero(); n]; 4];
+        for i in 0..steps {
+            let t = self.start + h * T::from_usize(i).unwrap();
+            (self.f)(t, &y, &mut k[0]);
+            for (j, &(c, previous)) in [(h / two, 0), (h / two, 1), (h, 2)].iter().enumerate() {
+                for ((s, &y), &k) in stage.iter_mut().zip(&y).zip(&k[previous]) {
+                    *s = y + c * k;
+                }
+                (self.f)(t + c, &stage, &mut k[j + 1]);
+            }
+            for (i, y) in y.iter_mut().enumerate() {
+                *y = *y + h / six * (k[0][i] + two * (k[1][i] + k[2][i]) + k[3][i]);
+            }
+
+            let t = if i + 1 == steps { end } else { t + h };
+            solution.push(t, &y);
+        }
+        solution.evaluations = 4 * steps;
+        solution
+    }
+
+    /// Solve up to `end` with the adaptive fifth order
+    /// [Dormand–Prince method](https://en.wikipedia.org/wiki/Dormand%E2%80%93Prince_method),
+    /// controlling the step size with the embedded fourth order solution.
+    /// The solution can be interpolated to fourth order between steps.
+    ///
+    /// ```
+    /// use quickmaths::ode::{DormandPrince, InitialValue, Status};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let mut problem = InitialValue::new(0., [1.], |t: f64, y: &[f64], dy: &mut [f64]| {
+    ///     dy[0] = -2. * t * y[0];
+    /// });
+    /// let dense = problem.dormand_prince(2., DormandPrince::default());
+    /// assert_eq!(dense.solution.status, Status::Completed);
+    ///
+    /// let (_, y) = dense.solution.last();
+    /// assert_relative_eq!(y[0], (-4f64).exp(), epsilon = 1e-8);
+    ///
+    /// // Between the steps
+    /// assert_relative_eq!(dense.at(0.5)[0], (-0.25f64).exp(), epsilon = 1e-7);
+    /// ```
+    pub fn dormand_prince(&mut self, end: T, dormand_prince: DormandPrince<T>) -> DenseSolution<T> {
+        dormand_prince.solve(self.start, &self.initial, end, &mut self.f)
+    }
+
+    /// Solve up to `end` with an adaptive second order
+    /// [Rosenbrock method](https://en.wikipedia.org/wiki/Rosenbrock_methods), which is linearly implicit
+    /// and L-stable, so it takes large steps on stiff problems where explicit methods can't.
+    ///
+    /// ```
+    /// use quickmaths::ode::{InitialValue, Rosenbrock, Status};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Robertson's chemical kinetics, a classic stiff problem
+    /// let mut problem = InitialValue::new(0., [1., 0., 0.], |_t, y: &[f64], dy: &mut [f64]| {
+    ///     dy[0] = -0.04 * y[0] + 1e4 * y[1] * y[2];
+    ///     dy[2] = 3e7 * y[1] * y[1];
+    ///     dy[1] = -dy[0] - dy[2];
+    /// });
+    /// let solution = problem.rosenbrock(40., Rosenbrock::default());
+    /// assert_eq!(solution.status, Status::Completed);
+    /// assert!(solution.times.len() < 200);
+    ///
+    /// let (_, y) = solution.last();
+    /// assert_relative_eq!(y[0], 0.7158, epsilon = 1e-3);
+    /// assert_relative_eq!(y[0] + y[1] + y[2], 1., epsilon = 1e-10);
+    /// ```
+    pub fn rosenbrock(&mut self, end: T, rosenbrock: Rosenbrock<T>) -> Solution<T> {
+        rosenbrock.solve(self.start, &self.initial, end, &mut self.f)
+    }
+}
+
+/// Reason a solver stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The solution reached the end time.
+    Completed,
+    /// The step limit was reached first.
+    MaxSteps,
+    /// The step size became too small relative to the time to make progress.
+    StepSizeUnderflow,
+}
+
+/// Times and states of a solution.
+#[derive(Clone, Debug)]
+pub struct Solution<T> {
+    pub times: Vec<T>,
+    /// States at each time, concatenated.
+    states: Vec<T>,
+    dimensions: usize,
+    /// Number of evaluations of the derivative, including those for finite difference Jacobians.
+    pub evaluations: usize,
+    pub status: Status,
+}
+
+impl<T: Copy> Solution<T> {
+    fn new(start: T, initial: &[T]) -> Self {
+        Self {
+            times: alloc::vec![start],
+            states: initial.to_vec(),
+            dimensions: initial.len(),
+            evaluations: 0,
+            status: Status::Completed,
+        }
+    }
+
+    fn push(&mut self, t: T, y: &[T]) {
+        self.times.push(t);
+        self.states.extend_from_slice(y);
+    }
+
+    /// State at the `i`th time.
+    pub fn state(&self, i: usize) -> &[T] {
+        &self.states[i * self.dimensions..(i + 1) * self.dimensions]
+    }
+
+    /// Last time and state reached.
+    pub fn last(&self) -> (T, &[T]) {
+        let i = self.times.len() - 1;
+        (self.times[i], self.state(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (T, &[T])> + '_ {
+        self.times
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| (t, self.state(i)))
+    }
+}
+
+/// Root mean square of the error scaled by `absolute + relative * max(|y|, |next|)`.
+fn error_norm<T>(error: &[T], y: &[T], next: &[T], absolute: T, relative: T) -> T
+where
+    T: Real + FromPrimitive,
+{
+    let sum = error
+        .iter()
+        .zip(y)
+        .zip(next)
+        .fold(T::zero(), |sum, ((&e, &y), &next)| {
+            let scale = absolute + relative * y.abs().max(next.abs());
+            let e = e / scale;
+            sum + e * e
+        });
+    (sum / T::from_usize(error.len().max(1)).unwrap()).sqrt()
+}
+
+/// Initial step size following Hairer, Nørsett and Wanner, for a method of order `order`.
+#[allow(clippy::too_many_arguments)]
+fn initial_step<F, T>(
+    f: &mut F,
+    t: T,
+    y: &[T],
+    dy: &[T],
+    direction: T,
+    order: i32,
+    absolute: T,
+    relative: T,
+) -> T
+where
+    F: FnMut(T, &[T], &mut [T]),
+    T: Real + FromPrimitive,
+{
+    let hundredth = T::from_f64(0.01).unwrap();
+    let small = T::from_f64(1e-6).unwrap();
+
+    let d0 = error_norm(y, y, y, absolute, relative);
+    let d1 = error_norm(dy, y, y, absolute, relative);
+    let h0 = if d0 < T::from_f64(1e-5).unwrap() || d1 < T::from_f64(1e-5).unwrap() {
+        small
+    } else {
+        hundredth * d0 / d1
+    };
+
+    let next: Vec<T> = y
+        .iter()
+        .zip(dy)
+        .map(|(&y, &dy)| y + direction * h0 * dy)
+        .collect();
+    let mut next_dy = alloc::vec![T::zero(); y.len()];
+    f(t + direction * h0, &next, &mut next_dy);
+    let change: Vec<T> = next_dy.iter().zip(dy).map(|(&a, &b)| a - b).collect();
+    let d2 = error_norm(&change, y, y, absolute, relative) / h0;
+
+    let max = d1.max(d2);
+    let h1 = if max <= T::from_f64(1e-15).unwrap() {
+        small.max(h0 * T::from_f64(1e-3).unwrap())
+    } else {
+        (hundredth / max).powf(T::one() / T::from_i32(order + 1).unwrap())
+    };
+    (T::from_u8(100).unwrap() * h0).min(h1)
+}
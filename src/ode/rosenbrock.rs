@@ -0,0 +1,228 @@
+use super::{error_norm, initial_step, Solution, Status};
+use crate::linalg::Lu;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Configuration for [`InitialValue::rosenbrock`](super::InitialValue::rosenbrock).
+///
+/// This is the Rosenbrock 2(3) pair by Shampine and Reichelt, with the Jacobian and time derivative of
+/// the system approximated by forward differences once per step.
+/// Steps are accepted when the root mean square of the estimated local error, scaled by
+/// `absolute_tolerance + relative_tolerance * |y|` for each component, is at most one.
+pub struct Rosenbrock<T> {
+    pub absolute_tolerance: T,
+    pub relative_tolerance: T,
+    pub initial_step: Option<T>,
+    pub max_steps: usize,
+}
+
+impl<T> Default for Rosenbrock<T>
+where
+    T: FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            absolute_tolerance: T::from_f64(1e-8).unwrap(),
+            relative_tolerance: T::from_f64(1e-6).unwrap(),
+            initial_step: None,
+            max_steps: 100_000,
+        }
+    }
+}
+
+impl<T> Rosenbrock<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn solve<F>(&self, start: T, initial: &[T], end: T, f: &mut F) -> Solution<T>
+    where
+        F: FnMut(T, &[T], &mut [T]),
+    {
+        let n = initial.len();
+        let two = T::from_u8(2).unwrap();
+        let sqrt2 = two.sqrt();
+        let d = T::one() / (two + sqrt2);
+        let e32 = T::from_u8(6).unwrap() + sqrt2;
+
+        let safety = T::from_f64(0.9).unwrap();
+        let (min_factor, max_factor) = (T::from_f64(0.2).unwrap(), T::from_u8(5).unwrap());
+        let exponent = T::one() / T::from_u8(3).unwrap();
+
+        let mut solution = Solution::new(start, initial);
+        let direction = if end < start { -T::one() } else { T::one() };
+
+        let mut t = start;
+        let mut y = initial.to_vec();
+        let mut f0 = alloc::vec![T::zero(); n];
+        f(t, &y, &mut f0);
+        let mut evaluations = 1;
+
+        let mut h = match self.initial_step {
+            Some(h) => h.abs(),
+            None => {
+                evaluations += 1;
+                initial_step(
+                    f,
+                    t,
+                    &y,
+                    &f0,
+                    direction,
+                    2,
+                    self.absolute_tolerance,
+                    self.relative_tolerance,
+                )
+            }
+        };
+
+        let mut jacobian = alloc::vec![T::zero(); n * n];
+        let mut time_derivative = alloc::vec![T::zero(); n];
+        let mut stale = true;
+
+        let (mut k1, mut k2, mut k3) = (
+            alloc::vec![T::zero(); n],
+            alloc::vec![T::zero(); n],
+            alloc::vec![T::zero(); n],
+        );
+        let (mut f1, mut f2) = (alloc::vec![T::zero(); n], alloc::vec![T::zero(); n]);
+        let mut stage = alloc::vec![T::zero(); n];
+        let mut error = alloc::vec![T::zero(); n];
+
+        let mut steps = 0;
+        while (end - t) * direction > T::zero() {
+            if steps == self.max_steps {
+                solution.status = Status::MaxSteps;
+                break;
+            }
+            if h <= T::from_u8(16).unwrap() * T::epsilon() * t.abs() {
+                solution.status = Status::StepSizeUnderflow;
+                break;
+            }
+            steps += 1;
+
+            if stale {
+                evaluations += differentiate(f, t, &y, &f0, &mut jacobian, &mut time_derivative);
+                stale = false;
+            }
+
+            // Don't step past the end
+            let last = h >= (end - t).abs();
+            let step = if last { end - t } else { direction * h };
+            let hd = step * d;
+
+            let w = jacobian
+                .iter()
+                .enumerate()
+                .map(|(i, &j)| {
+                    let identity = if i / n == i % n { T::one() } else { T::zero() };
+                    identity - hd * j
+                })
+                .collect();
+            let lu = match Lu::new(w, n) {
+                Some(lu) => lu,
+                None => {
+                    h = h / two;
+                    continue;
+                }
+            };
+
+            for i in 0..n {
+                k1[i] = f0[i] + hd * time_derivative[i];
+            }
+            lu.solve(&mut k1);
+
+            for i in 0..n {
+                stage[i] = y[i] + step / two * k1[i];
+            }
+            f(t + step / two, &stage, &mut f1);
+            for i in 0..n {
+                k2[i] = f1[i] - k1[i];
+            }
+            lu.solve(&mut k2);
+            for i in 0..n {
+                k2[i] = k2[i] + k1[i];
+                stage[i] = y[i] + step * k2[i];
+            }
+
+            f(t + step, &stage, &mut f2);
+            for i in 0..n {
+                k3[i] =
+                    f2[i] - e32 * (k2[i] - f1[i]) - two * (k1[i] - f0[i]) + hd * time_derivative[i];
+            }
+            lu.solve(&mut k3);
+            evaluations += 2;
+
+            let sixth = step / T::from_u8(6).unwrap();
+            for i in 0..n {
+                error[i] = sixth * (k1[i] - two * k2[i] + k3[i]);
+            }
+            let norm = error_norm(
+                &error,
+                &y,
+                &stage,
+                self.absolute_tolerance,
+                self.relative_tolerance,
+            );
+
+            let factor = if norm.is_zero() {
+                max_factor
+            } else {
+                (safety * norm.powf(-exponent))
+                    .max(min_factor)
+                    .min(max_factor)
+            };
+
+            if norm <= T::one() {
+                t = if last { end } else { t + step };
+                y.copy_from_slice(&stage);
+                solution.push(t, &y);
+                // The last evaluation is at the new state
+                core::mem::swap(&mut f0, &mut f2);
+                stale = true;
+                h = h * factor;
+            } else {
+                h = h * factor.min(T::one());
+            }
+        }
+
+        solution.evaluations = evaluations;
+        solution
+    }
+}
+
+/// Approximate the row-major Jacobian and the time derivative of `f` at `(t, y)` with forward differences,
+/// returning the number of evaluations.
+fn differentiate<F, T>(
+    f: &mut F,
+    t: T,
+    y: &[T],
+    dy: &[T],
+    jacobian: &mut [T],
+    time_derivative: &mut [T],
+) -> usize
+where
+    F: FnMut(T, &[T], &mut [T]),
+    T: Real,
+{
+    let n = y.len();
+    let scale = T::epsilon().sqrt();
+    let mut point = y.to_vec();
+    let mut shifted = alloc::vec![T::zero(); n];
+
+    for j in 0..n {
+        let step = scale * y[j].abs().max(T::one());
+        point[j] = y[j] + step;
+        f(t, &point, &mut shifted);
+        point[j] = y[j];
+
+        for i in 0..n {
+            jacobian[i * n + j] = (shifted[i] - dy[i]) / step;
+        }
+    }
+
+    let step = scale * t.abs().max(T::one());
+    f(t + step, y, &mut shifted);
+    for (derivative, (&shifted, &dy)) in time_derivative.iter_mut().zip(shifted.iter().zip(dy)) {
+        *derivative = (shifted - dy) / step;
+    }
+
+    n + 1
+}
@@ -0,0 +1,64 @@
+use super::{
+    line_search::{descend, Direction},
+    Gradient, Minimum,
+};
+use num::{traits::real::Real, FromPrimitive};
+
+/// Configuration for [`Minimize::gradient_descent`](super::Minimize::gradient_descent).
+///
+/// The search stops once the largest component of the gradient is within `gradient_tolerance`,
+/// the function decreases by a relative amount within `function_tolerance` in an iteration,
+/// or after `max_iterations`.
+pub struct GradientDescent<T> {
+    pub gradient_tolerance: T,
+    pub function_tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for GradientDescent<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            gradient_tolerance: T::epsilon().cbrt(),
+            function_tolerance: T::epsilon(),
+            max_iterations: 10_000,
+        }
+    }
+}
+
+impl<T> GradientDescent<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn minimize<F, G>(&self, start: &[T], f: &mut F, gradient: &mut G) -> Minimum<T>
+    where
+        F: FnMut(&[T]) -> T,
+        G: Gradient<T>,
+    {
+        descend(
+            start,
+            f,
+            gradient,
+            SteepestDescent,
+            self.gradient_tolerance,
+            self.function_tolerance,
+            self.max_iterations,
+        )
+    }
+}
+
+struct SteepestDescent;
+
+impl<T: Real> Direction<T> for SteepestDescent {
+    fn direction(&mut self, gradient: &[T], direction: &mut [T]) {
+        for (d, &g) in direction.iter_mut().zip(gradient) {
+            *d = -g;
+        }
+    }
+
+    fn unit_step(&self) -> bool {
+        false
+    }
+}
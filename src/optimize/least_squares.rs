@@ -0,0 +1,371 @@
+//! Nonlinear least squares
+
+use super::{dot, max_norm, FiniteDifference, Status};
+use crate::linalg::Lu;
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Jacobian of a residual function.
+///
+/// This is implemented for closures `FnMut(&[T], &mut [T])` writing the Jacobian at a point into
+/// the second slice, and by [`FiniteDifference`] to approximate it from the residuals themselves.
+pub trait Jacobian<T> {
+    /// Write the Jacobian of the residual function `f` at `x` into `jacobian`,
+    /// row-major with a row for each residual, where `residuals` are the residuals at `x`.
+    fn jacobian<F>(&mut self, f: &mut F, x: &[T], residuals: &[T], jacobian: &mut [T])
+    where
+        F: FnMut(&[T], &mut [T]);
+}
+
+impl<J, T> Jacobian<T> for J
+where
+    J: FnMut(&[T], &mut [T]),
+{
+    fn jacobian<F>(&mut self, _f: &mut F, x: &[T], _residuals: &[T], jacobian: &mut [T])
+    where
+        F: FnMut(&[T], &mut [T]),
+    {
+        self(x, jacobian)
+    }
+}
+
+impl<T> Jacobian<T> for FiniteDifference
+where
+    T: Real + FromPrimitive,
+{
+    /// Approximate the Jacobian with forward differences, with a step of `epsilon^(1/2)`
+    /// scaled by each coordinate.
+    fn jacobian<F>(&mut self, f: &mut F, x: &[T], residuals: &[T], jacobian: &mut [T])
+    where
+        F: FnMut(&[T], &mut [T]),
+    {
+        let n = x.len();
+        let mut point = x.to_vec();
+        let mut shifted = alloc::vec![T::zero(); residuals.len()];
+        let scale = T::epsilon().sqrt();
+        for j in 0..n {
+            let step = scale * x[j].abs().max(T::one());
+            point[j] = x[j] + step;
+            f(&point, &mut shifted);
+            point[j] = x[j];
+
+            for (i, (&shifted, &residual)) in shifted.iter().zip(residuals).enumerate() {
+                jacobian[i * n + j] = (shifted - residual) / step;
+            }
+        }
+    }
+}
+
+/// Least squares problem of minimizing the sum of squares of `residuals` residuals written by `f`,
+/// starting from the given `parameters`.
+///
+/// The Jacobian is approximated with [`FiniteDifference`] unless one is provided with
+/// [`LeastSquares::jacobian`].
+pub struct LeastSquares<F, T, J = FiniteDifference> {
+    parameters: Vec<T>,
+    residuals: usize,
+    f: F,
+    jacobian: J,
+}
+
+impl<F, T> LeastSquares<F, T> {
+    pub fn new<P>(parameters: P, residuals: usize, f: F) -> Self
+    where
+        P: IntoIterator<Item = T>,
+    {
+        Self {
+            parameters: parameters.into_iter().collect(),
+            residuals,
+            f,
+            jacobian: FiniteDifference,
+        }
+    }
+}
+
+impl<F, T, J> LeastSquares<F, T, J> {
+    /// Use `jacobian` for the Jacobian of the residuals instead.
+    pub fn jacobian<K>(self, jacobian: K) -> LeastSquares<F, T, K> {
+        LeastSquares {
+            parameters: self.parameters,
+            residuals: self.residuals,
+            f: self.f,
+            jacobian,
+        }
+    }
+}
+
+impl<F, T, J> LeastSquares<F, T, J>
+where
+    F: FnMut(&[T], &mut [T]),
+    J: Jacobian<T>,
+    T: Real + FromPrimitive,
+{
+    /// Find the best fit with the [Levenberg–Marquardt algorithm](https://en.wikipedia.org/wiki/Levenberg%E2%80%93Marquardt_algorithm),
+    /// interpolating between Gauss–Newton and gradient descent steps by adapting the damping.
+    ///
+    /// ```
+    /// use quickmaths::optimize::least_squares::{LeastSquares, LevenbergMarquardt};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Noisy samples of 3 e^(-t / 2)
+    /// let data: Vec<(f64, f64)> = (0..20)
+    ///     .map(|i| {
+    ///         let t = i as f64 / 4.;
+    ///         (t, 3. * (-t / 2.).exp() + 0.01 * (7. * t).sin())
+    ///     })
+    ///     .collect();
+    ///
+    /// let fit = LeastSquares::new([1., 1.], data.len(), |p: &[f64], residuals: &mut [f64]| {
+    ///     for (r, (t, y)) in residuals.iter_mut().zip(&data) {
+    ///         *r = p[0] * (-t / p[1]).exp() - y;
+    ///     }
+    /// })
+    /// .levenberg_marquardt(LevenbergMarquardt::default());
+    ///
+    /// assert!(fit.converged());
+    /// assert_relative_eq!(fit.parameters[0], 3., epsilon = 1e-2);
+    /// assert_relative_eq!(fit.parameters[1], 2., epsilon = 1e-2);
+    /// assert_eq!(fit.degrees_of_freedom, 18);
+    /// assert!(fit.reduced_chi_squared().unwrap() < 1e-4);
+    ///
+    /// let errors = fit.standard_errors().unwrap();
+    /// assert!(errors[0] < 1e-2 && errors[1] < 1e-2);
+    /// ```
+    pub fn levenberg_marquardt(&mut self, levenberg_marquardt: LevenbergMarquardt<T>) -> Fit<T> {
+        levenberg_marquardt.fit(
+            &self.parameters,
+            self.residuals,
+            &mut self.f,
+            &mut self.jacobian,
+        )
+    }
+}
+
+/// Configuration for [`LeastSquares::levenberg_marquardt`].
+///
+/// The damping `λ` starts at `initial_damping` and scales the diagonal of `JᵀJ` as in Marquardt's method,
+/// so each step solves `(JᵀJ + λ diag(JᵀJ)) δ = -Jᵀr` and is invariant to the scale of the parameters.
+/// The search stops once the largest component of the gradient of the sum of squares is within
+/// `gradient_tolerance`, the sum of squares decreases by a relative amount within `function_tolerance`,
+/// a step is within `step_tolerance` relative to the parameters, or after `max_iterations`.
+pub struct LevenbergMarquardt<T> {
+    pub initial_damping: T,
+    pub gradient_tolerance: T,
+    pub function_tolerance: T,
+    pub step_tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for LevenbergMarquardt<T>
+where
+    T: Real + FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            initial_damping: T::from_f64(1e-3).unwrap(),
+            gradient_tolerance: T::epsilon(),
+            function_tolerance: T::epsilon(),
+            step_tolerance: T::epsilon().sqrt(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl<T> LevenbergMarquardt<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn fit<F, J>(&self, start: &[T], m: usize, f: &mut F, jacobian: &mut J) -> Fit<T>
+    where
+        F: FnMut(&[T], &mut [T]),
+        J: Jacobian<T>,
+    {
+        let n = start.len();
+        let mut evaluations = 0;
+        let mut f = |x: &[T], residuals: &mut [T]| {
+            evaluations += 1;
+            f(x, residuals)
+        };
+
+        let mut x = start.to_vec();
+        let mut residuals = alloc::vec![T::zero(); m];
+        f(&x, &mut residuals);
+        let mut cost = dot(&residuals, &residuals);
+
+        let mut jacobian_matrix = alloc::vec![T::zero(); m * n];
+        let mut normal = alloc::vec![T::zero(); n * n];
+        let mut gradient = alloc::vec![T::zero(); n];
+        let mut linearize =
+            |f: &mut _, x: &[T], residuals: &[T], normal: &mut [T], gradient: &mut [T]| {
+                jacobian.jacobian(f, x, residuals, &mut jacobian_matrix);
+                normal_equations(&jacobian_matrix, residuals, n, normal, gradient);
+            };
+        linearize(&mut f, &x, &residuals, &mut normal, &mut gradient);
+
+        let diagonal = |normal: &[T], i: usize| {
+            let d = normal[i * n + i];
+            if d > T::zero() {
+                d
+            } else {
+                T::one()
+            }
+        };
+        let mut damping = self.initial_damping;
+        let two = T::from_u8(2).unwrap();
+        let mut growth = two;
+
+        let mut next = alloc::vec![T::zero(); n];
+        let mut next_residuals = alloc::vec![T::zero(); m];
+        let mut step = alloc::vec![T::zero(); n];
+        let mut status = Status::MaxIterations;
+        let mut iterations = 0;
+        while iterations < self.max_iterations {
+            if max_norm(&gradient) <= self.gradient_tolerance {
+                status = Status::GradientTolerance;
+                break;
+            }
+            iterations += 1;
+
+            // Solve (JᵀJ + λ diag(JᵀJ)) δ = -Jᵀr
+            let mut damped = normal.clone();
+            for i in 0..n {
+                damped[i * n + i] = damped[i * n + i] + damping * diagonal(&normal, i);
+            }
+            for (s, &g) in step.iter_mut().zip(&gradient) {
+                *s = -g;
+            }
+            match Lu::new(damped, n) {
+                Some(lu) => lu.solve(&mut step),
+                None => {
+                    damping = damping * growth;
+                    growth = growth * two;
+                    continue;
+                }
+            }
+
+            let size = dot(&step, &step).sqrt();
+            if size <= self.step_tolerance * (dot(&x, &x).sqrt() + self.step_tolerance) {
+                status = Status::StepTolerance;
+                break;
+            }
+
+            for ((next, &x), &s) in next.iter_mut().zip(&x).zip(&step) {
+                *next = x + s;
+            }
+            f(&next, &mut next_residuals);
+            let next_cost = dot(&next_residuals, &next_residuals);
+
+            // Ratio of the actual reduction to the reduction predicted by the linear model
+            let predicted = (0..n).fold(T::zero(), |sum, i| {
+                sum + step[i] * (damping * diagonal(&normal, i) * step[i] - gradient[i])
+            });
+            let ratio = (cost - next_cost) / predicted;
+
+            if ratio > T::zero() {
+                let reduction = cost - next_cost;
+                let previous = cost;
+                core::mem::swap(&mut x, &mut next);
+                core::mem::swap(&mut residuals, &mut next_residuals);
+                cost = next_cost;
+                linearize(&mut f, &x, &residuals, &mut normal, &mut gradient);
+
+                let cube = (two * ratio - T::one()).powi(3);
+                damping = damping * (T::one() / T::from_u8(3).unwrap()).max(T::one() - cube);
+                growth = two;
+
+                if reduction <= self.function_tolerance * previous {
+                    status = Status::FunctionTolerance;
+                    break;
+                }
+            } else {
+                damping = damping * growth;
+                growth = growth * two;
+            }
+        }
+
+        let degrees_of_freedom = m.saturating_sub(n);
+        let covariance = if degrees_of_freedom > 0 {
+            let variance = cost / T::from_usize(degrees_of_freedom).unwrap();
+            Lu::new(normal, n).map(|lu| lu.inverse().into_iter().map(|c| c * variance).collect())
+        } else {
+            None
+        };
+
+        Fit {
+            parameters: x,
+            covariance,
+            chi_squared: cost,
+            degrees_of_freedom,
+            iterations,
+            evaluations,
+            status,
+        }
+    }
+}
+
+/// Write `JᵀJ` and the gradient `Jᵀr` of half the sum of squares.
+fn normal_equations<T: Real>(
+    jacobian: &[T],
+    residuals: &[T],
+    n: usize,
+    normal: &mut [T],
+    gradient: &mut [T],
+) {
+    for i in 0..n {
+        gradient[i] = jacobian
+            .chunks(n)
+            .zip(residuals)
+            .fold(T::zero(), |sum, (row, &r)| sum + row[i] * r);
+        for j in 0..n {
+            normal[i * n + j] = jacobian
+                .chunks(n)
+                .fold(T::zero(), |sum, row| sum + row[i] * row[j]);
+        }
+    }
+}
+
+/// Best fit parameters of a least squares problem.
+#[derive(Clone, Debug)]
+pub struct Fit<T> {
+    pub parameters: Vec<T>,
+    /// Estimated covariance of the parameters, row-major, as `(JᵀJ)⁻¹` scaled by the reduced
+    /// chi-squared. This is `None` if there are no degrees of freedom or `JᵀJ` is singular.
+    pub covariance: Option<Vec<T>>,
+    /// Sum of squared residuals, which is the chi-squared statistic for residuals weighted by
+    /// the inverse of their standard deviations.
+    pub chi_squared: T,
+    /// Number of residuals minus the number of parameters.
+    pub degrees_of_freedom: usize,
+    pub iterations: usize,
+    /// Number of evaluations of the residuals, including those for finite difference Jacobians.
+    pub evaluations: usize,
+    pub status: Status,
+}
+
+impl<T> Fit<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub fn converged(&self) -> bool {
+        matches!(
+            self.status,
+            Status::GradientTolerance | Status::FunctionTolerance | Status::StepTolerance
+        )
+    }
+
+    /// Chi-squared per degree of freedom, which is close to one for a good fit with correctly
+    /// weighted residuals. This is `None` if there are no degrees of freedom.
+    pub fn reduced_chi_squared(&self) -> Option<T> {
+        if self.degrees_of_freedom == 0 {
+            return None;
+        }
+        Some(self.chi_squared / T::from_usize(self.degrees_of_freedom).unwrap())
+    }
+
+    /// Standard errors of the parameters, from the diagonal of the covariance.
+    pub fn standard_errors(&self) -> Option<Vec<T>> {
+        let n = self.parameters.len();
+        let covariance = self.covariance.as_ref()?;
+        Some((0..n).map(|i| covariance[i * n + i].sqrt()).collect())
+    }
+}
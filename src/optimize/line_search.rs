@@ -0,0 +1,285 @@
+use super::{dot, max_norm, Gradient, Minimum, Status};
+use core::cmp::Ordering;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Objective function and its gradient, counting evaluations of the function.
+pub(super) struct Objective<'a, F, G> {
+    f: &'a mut F,
+    gradient: &'a mut G,
+    pub(super) evaluations: usize,
+}
+
+impl<'a, F, G> Objective<'a, F, G> {
+    pub(super) fn new(f: &'a mut F, gradient: &'a mut G) -> Self {
+        Self {
+            f,
+            gradient,
+            evaluations: 0,
+        }
+    }
+
+    pub(super) fn value<T>(&mut self, x: &[T]) -> T
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        self.evaluations += 1;
+        (self.f)(x)
+    }
+
+    pub(super) fn gradient<T>(&mut self, x: &[T], gradient: &mut [T])
+    where
+        F: FnMut(&[T]) -> T,
+        G: Gradient<T>,
+    {
+        let (f, evaluations) = (&mut self.f, &mut self.evaluations);
+        self.gradient.gradient(
+            &mut |x: &[T]| {
+                *evaluations += 1;
+                f(x)
+            },
+            x,
+            gradient,
+        );
+    }
+}
+
+/// Search along `direction` from `x` for a step satisfying the strong Wolfe conditions,
+/// following algorithms 3.5 and 3.6 of Nocedal and Wright's *Numerical Optimization*.
+///
+/// On success the step and the function value are returned, with the new point and its gradient
+/// written into `next` and `next_gradient`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn line_search<F, G, T>(
+    objective: &mut Objective<F, G>,
+    x: &[T],
+    value: T,
+    gradient: &[T],
+    direction: &[T],
+    initial_step: T,
+    next: &mut [T],
+    next_gradient: &mut [T],
+) -> Option<(T, T)>
+where
+    F: FnMut(&[T]) -> T,
+    G: Gradient<T>,
+    T: Real + FromPrimitive,
+{
+    const MAX_EVALUATIONS: usize = 30;
+
+    let sufficient_decrease = T::from_f64(1e-4).unwrap();
+    let curvature = T::from_f64(0.9).unwrap();
+    let two = T::from_u8(2).unwrap();
+
+    let slope = dot(gradient, direction);
+    if slope >= T::zero() {
+        return None;
+    }
+
+    let evaluate = |objective: &mut Objective<F, G>, step: T, next: &mut [T]| {
+        for ((next, &x), &d) in next.iter_mut().zip(x).zip(direction) {
+            *next = x + step * d;
+        }
+        objective.value(next)
+    };
+    // Whether a trial value fails to decrease sufficiently, including NaN
+    let armijo = |step: T, trial: T| {
+        let bound = value + sufficient_decrease * step * slope;
+        !matches!(
+            trial.partial_cmp(&bound),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    };
+
+    // Bracket a step satisfying the conditions
+    let (mut low, mut high) = ((T::zero(), value, slope), None);
+    let mut step = initial_step;
+    let mut evaluations = 0;
+    while evaluations < MAX_EVALUATIONS {
+        let trial = evaluate(objective, step, next);
+        evaluations += 1;
+        if armijo(step, trial) || (low.0 > T::zero() && trial >= low.1) {
+            high = Some((step, trial));
+            break;
+        }
+
+        objective.gradient(next, next_gradient);
+        let trial_slope = dot(next_gradient, direction);
+        if trial_slope.abs() <= -curvature * slope {
+            return Some((step, trial));
+        }
+        if trial_slope >= T::zero() {
+            high = Some((low.0, low.1));
+            low = (step, trial, trial_slope);
+            break;
+        }
+
+        low = (step, trial, trial_slope);
+        step = step * two;
+    }
+    let (mut high_step, mut high_value) = high?;
+
+    // Zoom in on the step between `low` and `high`
+    let tenth = T::from_f64(0.1).unwrap();
+    while evaluations < MAX_EVALUATIONS {
+        let (low_step, low_value, low_slope) = low;
+        let width = high_step - low_step;
+
+        // Minimum of the quadratic through the low value and slope and the high value,
+        // or the midpoint when it's too close to either end
+        let denominator = two * (high_value - low_value - low_slope * width);
+        let mut step = low_step - low_slope * width * width / denominator;
+        let (min, max) = if low_step < high_step {
+            (low_step, high_step)
+        } else {
+            (high_step, low_step)
+        };
+        let margin = tenth * width.abs();
+        if !(step > min + margin && step < max - margin) {
+            step = low_step + width / two;
+        }
+
+        let trial = evaluate(objective, step, next);
+        evaluations += 1;
+        if armijo(step, trial) || trial >= low_value {
+            high_step = step;
+            high_value = trial;
+            continue;
+        }
+
+        objective.gradient(next, next_gradient);
+        let trial_slope = dot(next_gradient, direction);
+        if trial_slope.abs() <= -curvature * slope {
+            return Some((step, trial));
+        }
+        if trial_slope * width >= T::zero() {
+            high_step = low_step;
+            high_value = low_value;
+        }
+        low = (step, trial, trial_slope);
+    }
+
+    // Settle for sufficient decrease without the curvature condition
+    let (low_step, _, _) = low;
+    if low_step > T::zero() {
+        let trial = evaluate(objective, low_step, next);
+        objective.gradient(next, next_gradient);
+        return Some((low_step, trial));
+    }
+    None
+}
+
+/// Search direction of a gradient-based method.
+pub(super) trait Direction<T> {
+    /// Write the search direction at a point with `gradient` into `direction`.
+    fn direction(&mut self, gradient: &[T], direction: &mut [T]);
+
+    /// Whether a unit step is the natural first trial of the line search, as for quasi-Newton
+    /// directions, rather than a step predicted from the previous iteration.
+    fn unit_step(&self) -> bool {
+        true
+    }
+
+    /// Update the method after taking `step` with a `change` in the gradient.
+    fn update(&mut self, _step: &[T], _change: &[T]) {}
+
+    /// Forget accumulated curvature information, returning whether there was any.
+    fn reset(&mut self) -> bool {
+        false
+    }
+}
+
+/// Minimize by repeated line searches along the directions of `method`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn descend<F, G, T, D>(
+    start: &[T],
+    f: &mut F,
+    gradient: &mut G,
+    mut method: D,
+    gradient_tolerance: T,
+    function_tolerance: T,
+    max_iterations: usize,
+) -> Minimum<T>
+where
+    F: FnMut(&[T]) -> T,
+    G: Gradient<T>,
+    T: Real + FromPrimitive,
+    D: Direction<T>,
+{
+    let n = start.len();
+    let mut objective = Objective::new(f, gradient);
+
+    let mut x = start.to_vec();
+    let mut value = objective.value(&x);
+    let mut g = alloc::vec![T::zero(); n];
+    objective.gradient(&x, &mut g);
+
+    let mut next = alloc::vec![T::zero(); n];
+    let mut next_gradient = alloc::vec![T::zero(); n];
+    let mut direction = alloc::vec![T::zero(); n];
+    let mut step = alloc::vec![T::zero(); n];
+    let mut change = alloc::vec![T::zero(); n];
+
+    // Step length and slope along the direction of the previous iteration
+    let mut previous: Option<(T, T)> = None;
+    let mut status = Status::MaxIterations;
+    let mut iterations = 0;
+    while iterations < max_iterations {
+        if max_norm(&g) <= gradient_tolerance {
+            status = Status::GradientTolerance;
+            break;
+        }
+
+        method.direction(&g, &mut direction);
+        let slope = dot(&g, &direction);
+        let initial_step = match previous {
+            Some(_) if method.unit_step() => T::one(),
+            Some((length, previous_slope)) => length * previous_slope / slope,
+            None => max_norm(&direction).recip().min(T::one()),
+        };
+
+        let Some((length, next_value)) = line_search(
+            &mut objective,
+            &x,
+            value,
+            &g,
+            &direction,
+            initial_step,
+            &mut next,
+            &mut next_gradient,
+        ) else {
+            // Retry along the gradient before giving up
+            if method.reset() {
+                previous = None;
+                continue;
+            }
+            status = Status::LineSearchFailed;
+            break;
+        };
+        iterations += 1;
+
+        for i in 0..n {
+            step[i] = next[i] - x[i];
+            change[i] = next_gradient[i] - g[i];
+        }
+        method.update(&step, &change);
+        core::mem::swap(&mut x, &mut next);
+        core::mem::swap(&mut g, &mut next_gradient);
+
+        let decrease = value - next_value;
+        let scale = value.abs().max(next_value.abs()).max(T::one());
+        value = next_value;
+        previous = Some((length, slope));
+        if decrease <= function_tolerance * scale {
+            status = Status::FunctionTolerance;
+            break;
+        }
+    }
+
+    Minimum {
+        point: x,
+        value,
+        iterations,
+        evaluations: objective.evaluations,
+        status,
+    }
+}
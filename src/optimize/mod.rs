@@ -0,0 +1,279 @@
+//! Optimization
+
+mod gradient_descent;
+pub use gradient_descent::GradientDescent;
+
+pub mod least_squares;
+
+mod line_search;
+
+mod nelder_mead;
+pub use nelder_mead::NelderMead;
+
+mod quasi_newton;
+pub use quasi_newton::{Bfgs, Lbfgs};
+
+pub mod scalar;
+
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Gradient of an objective function.
+///
+/// This is implemented for closures `FnMut(&[T], &mut [T])` writing the gradient at a point
+/// into the second slice, and by [`FiniteDifference`] to approximate it from the function itself.
+pub trait Gradient<T> {
+    /// Write the gradient of `f` at `x` into `gradient`.
+    fn gradient<F>(&mut self, f: &mut F, x: &[T], gradient: &mut [T])
+    where
+        F: FnMut(&[T]) -> T;
+}
+
+impl<G, T> Gradient<T> for G
+where
+    G: FnMut(&[T], &mut [T]),
+{
+    fn gradient<F>(&mut self, _f: &mut F, x: &[T], gradient: &mut [T])
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        self(x, gradient)
+    }
+}
+
+/// Approximate derivatives with finite differences.
+pub struct FiniteDifference;
+
+impl<T> Gradient<T> for FiniteDifference
+where
+    T: Real + FromPrimitive,
+{
+    /// Approximate the gradient with central differences, with a step of `epsilon^(1/3)`
+    /// scaled by each coordinate.
+    fn gradient<F>(&mut self, f: &mut F, x: &[T], gradient: &mut [T])
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        let mut point = x.to_vec();
+        let scale = T::epsilon().cbrt();
+        for (i, derivative) in gradient.iter_mut().enumerate() {
+            let step = scale * x[i].abs().max(T::one());
+            point[i] = x[i] + step;
+            let forward = f(&point);
+            point[i] = x[i] - step;
+            let backward = f(&point);
+            point[i] = x[i];
+
+            *derivative = (forward - backward) / (step + step);
+        }
+    }
+}
+
+/// Reason a minimization stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The largest component of the gradient is within the gradient tolerance.
+    GradientTolerance,
+    /// The relative decrease of the function in the last iteration is within the function tolerance.
+    FunctionTolerance,
+    /// The function values and coordinates of the simplex are within the tolerance of the best vertex.
+    SimplexTolerance,
+    /// The last step is within the step tolerance relative to the point.
+    StepTolerance,
+    /// The interval known to contain the minimum of a function of one variable is within the tolerance.
+    IntervalTolerance,
+    /// The iteration limit was reached first.
+    MaxIterations,
+    /// No step along the search direction sufficiently decreased the function.
+    LineSearchFailed,
+}
+
+/// Approximate minimum of a function of several variables.
+#[derive(Clone, Debug)]
+pub struct Minimum<T> {
+    pub point: Vec<T>,
+    /// Value of the function at `point`.
+    pub value: T,
+    pub iterations: usize,
+    /// Number of evaluations of the function, including those for finite difference gradients.
+    pub evaluations: usize,
+    pub status: Status,
+}
+
+impl<T> Minimum<T> {
+    pub fn converged(&self) -> bool {
+        matches!(
+            self.status,
+            Status::GradientTolerance
+                | Status::FunctionTolerance
+                | Status::SimplexTolerance
+                | Status::StepTolerance
+                | Status::IntervalTolerance
+        )
+    }
+}
+
+/// Minimum of function `f` of several variables, searched for from `start`.
+///
+/// Gradient-based methods use `gradient`, approximated with [`FiniteDifference`] unless
+/// one is provided with [`Minimize::gradient`].
+///
+/// ```
+/// use quickmaths::optimize::{Bfgs, Minimize};
+/// use quickmaths::stats::{Density, NormalDistribution};
+/// use approx::assert_relative_eq;
+///
+/// // Fit a normal distribution to samples by maximum likelihood
+/// let samples = [1.2, 0.4, 2.1, 1.7, 0.9, 1.5];
+/// let negative_log_likelihood = |parameters: &[f64]| {
+///     let normal = NormalDistribution::new(parameters[0], parameters[1].exp());
+///     -samples.iter().map(|x| normal.pdf(x).ln()).sum::<f64>()
+/// };
+///
+/// let minimum = Minimize::new([0., 0.], negative_log_likelihood).bfgs(Bfgs::default());
+/// assert!(minimum.converged());
+///
+/// let mean = samples.iter().sum::<f64>() / 6.;
+/// let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 6.;
+/// assert_relative_eq!(minimum.point[0], mean, epsilon = 1e-6);
+/// assert_relative_eq!(minimum.point[1].exp(), variance.sqrt(), epsilon = 1e-6);
+/// ```
+pub struct Minimize<F, T, G = FiniteDifference> {
+    start: Vec<T>,
+    f: F,
+    gradient: G,
+}
+
+impl<F, T> Minimize<F, T> {
+    pub fn new<S>(start: S, f: F) -> Self
+    where
+        S: IntoIterator<Item = T>,
+    {
+        Self {
+            start: start.into_iter().collect(),
+            f,
+            gradient: FiniteDifference,
+        }
+    }
+}
+
+impl<F, T, G> Minimize<F, T, G> {
+    /// Use `gradient` for the gradient of the function instead.
+    pub fn gradient<H>(self, gradient: H) -> Minimize<F, T, H> {
+        Minimize {
+            start: self.start,
+            f: self.f,
+            gradient,
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.start.len()
+    }
+}
+
+impl<F, T, G> Minimize<F, T, G>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real + FromPrimitive,
+{
+    /// Find the minimum with the [Nelder–Mead method](https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method),
+    /// which only evaluates the function, so it also works for noisy or non-smooth functions.
+    ///
+    /// ```
+    /// use quickmaths::optimize::{Minimize, NelderMead};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rosenbrock = |x: &[f64]| (1. - x[0]).powi(2) + 100. * (x[1] - x[0] * x[0]).powi(2);
+    /// let minimum = Minimize::new([-1.2, 1.], rosenbrock).nelder_mead(NelderMead::default());
+    ///
+    /// assert!(minimum.converged());
+    /// assert_relative_eq!(minimum.point[0], 1., epsilon = 1e-4);
+    /// assert_relative_eq!(minimum.point[1], 1., epsilon = 1e-4);
+    /// ```
+    pub fn nelder_mead(&mut self, nelder_mead: NelderMead<T>) -> Minimum<T> {
+        nelder_mead.minimize(&self.start, &mut self.f)
+    }
+}
+
+impl<F, T, G> Minimize<F, T, G>
+where
+    F: FnMut(&[T]) -> T,
+    G: Gradient<T>,
+    T: Real + FromPrimitive,
+{
+    /// Find the minimum with [gradient descent](https://en.wikipedia.org/wiki/Gradient_descent),
+    /// stepping along the negative gradient with a line search.
+    /// This converges slowly for badly conditioned functions.
+    ///
+    /// ```
+    /// use quickmaths::optimize::{GradientDescent, Minimize};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let minimum = Minimize::new([3., -2.], |x: &[f64]| (x[0] - 1.).powi(2) + 2. * x[1] * x[1])
+    ///     .gradient(|x: &[f64], gradient: &mut [f64]| {
+    ///         gradient[0] = 2. * (x[0] - 1.);
+    ///         gradient[1] = 4. * x[1];
+    ///     })
+    ///     .gradient_descent(GradientDescent::default());
+    ///
+    /// assert!(minimum.converged());
+    /// assert_relative_eq!(minimum.point[0], 1., epsilon = 1e-5);
+    /// assert_relative_eq!(minimum.point[1], 0., epsilon = 1e-5);
+    /// ```
+    pub fn gradient_descent(&mut self, gradient_descent: GradientDescent<T>) -> Minimum<T> {
+        gradient_descent.minimize(&self.start, &mut self.f, &mut self.gradient)
+    }
+
+    /// Find the minimum with the [BFGS](https://en.wikipedia.org/wiki/Broyden%E2%80%93Fletcher%E2%80%93Goldfarb%E2%80%93Shanno_algorithm)
+    /// quasi-Newton method, which builds up an approximation of the inverse Hessian from gradients.
+    ///
+    /// ```
+    /// use quickmaths::optimize::{Bfgs, Minimize};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let rosenbrock = |x: &[f64]| (1. - x[0]).powi(2) + 100. * (x[1] - x[0] * x[0]).powi(2);
+    /// let minimum = Minimize::new([-1.2, 1.], rosenbrock).bfgs(Bfgs::default());
+    ///
+    /// assert!(minimum.converged());
+    /// assert_relative_eq!(minimum.point[0], 1., epsilon = 1e-5);
+    /// assert_relative_eq!(minimum.point[1], 1., epsilon = 1e-5);
+    /// ```
+    pub fn bfgs(&mut self, bfgs: Bfgs<T>) -> Minimum<T> {
+        bfgs.minimize(&self.start, &mut self.f, &mut self.gradient)
+    }
+
+    /// Find the minimum with the [limited-memory BFGS](https://en.wikipedia.org/wiki/Limited-memory_BFGS)
+    /// method, which only keeps the last few steps and gradient changes instead of a dense matrix,
+    /// so it scales to many variables.
+    ///
+    /// ```
+    /// use quickmaths::optimize::{Lbfgs, Minimize};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Extended Rosenbrock function in 20 dimensions
+    /// let rosenbrock = |x: &[f64]| {
+    ///     x.windows(2)
+    ///         .map(|w| (1. - w[0]).powi(2) + 100. * (w[1] - w[0] * w[0]).powi(2))
+    ///         .sum::<f64>()
+    /// };
+    /// let minimum = Minimize::new([0.; 20], rosenbrock).lbfgs(Lbfgs::default());
+    ///
+    /// assert!(minimum.converged());
+    /// for x in minimum.point {
+    ///     assert_relative_eq!(x, 1., epsilon = 1e-4);
+    /// }
+    /// ```
+    pub fn lbfgs(&mut self, lbfgs: Lbfgs<T>) -> Minimum<T> {
+        lbfgs.minimize(&self.start, &mut self.f, &mut self.gradient)
+    }
+}
+
+fn dot<T: Real>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).fold(T::zero(), |sum, (&a, &b)| sum + a * b)
+}
+
+/// Largest absolute value of the components of `x`.
+fn max_norm<T: Real>(x: &[T]) -> T {
+    x.iter().fold(T::zero(), |max, x| max.max(x.abs()))
+}
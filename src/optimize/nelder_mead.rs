@@ -0,0 +1,151 @@
+use super::{Minimum, Status};
+use alloc::vec::Vec;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Configuration for [`Minimize::nelder_mead`](super::Minimize::nelder_mead).
+///
+/// The initial simplex offsets each coordinate of the start by `initial_step` times its magnitude,
+/// or by `initial_step` itself for zero coordinates. The search stops once the function values
+/// and coordinates of every vertex are within `tolerance` of the best vertex, or after `max_iterations`.
+pub struct NelderMead<T> {
+    pub initial_step: T,
+    pub tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for NelderMead<T>
+where
+    T: Real + FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            initial_step: T::from_f64(0.05).unwrap(),
+            tolerance: T::epsilon().sqrt(),
+            max_iterations: 5000,
+        }
+    }
+}
+
+impl<T> NelderMead<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn minimize<F>(&self, start: &[T], f: &mut F) -> Minimum<T>
+    where
+        F: FnMut(&[T]) -> T,
+    {
+        let n = start.len();
+        let two = T::from_u8(2).unwrap();
+        let half = T::one() / two;
+        let mut evaluations = 0;
+        let mut evaluate = |x: &[T]| {
+            evaluations += 1;
+            f(x)
+        };
+
+        let mut simplex: Vec<(Vec<T>, T)> = Vec::with_capacity(n + 1);
+        simplex.push((start.to_vec(), evaluate(start)));
+        for i in 0..n {
+            let mut vertex = start.to_vec();
+            vertex[i] = if vertex[i].is_zero() {
+                self.initial_step
+            } else {
+                vertex[i] + self.initial_step * vertex[i].abs()
+            };
+            let value = evaluate(&vertex);
+            simplex.push((vertex, value));
+        }
+
+        let mut centroid = alloc::vec![T::zero(); n];
+        let point = |centroid: &[T], worst: &[T], scale: T| -> Vec<T> {
+            centroid
+                .iter()
+                .zip(worst)
+                .map(|(&c, &w)| c + scale * (c - w))
+                .collect()
+        };
+
+        let mut status = Status::MaxIterations;
+        let mut iterations = 0;
+        while iterations < self.max_iterations {
+            simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal));
+
+            let (best, best_value) = (&simplex[0].0, simplex[0].1);
+            let converged = simplex[1..].iter().all(|(vertex, value)| {
+                (*value - best_value).abs() <= self.tolerance
+                    && vertex
+                        .iter()
+                        .zip(best)
+                        .all(|(&x, &b)| (x - b).abs() <= self.tolerance)
+            });
+            if converged {
+                status = Status::SimplexTolerance;
+                break;
+            }
+            iterations += 1;
+
+            // Centroid of every vertex but the worst
+            for (i, c) in centroid.iter_mut().enumerate() {
+                *c = simplex[..n]
+                    .iter()
+                    .fold(T::zero(), |sum, (vertex, _)| sum + vertex[i])
+                    / T::from_usize(n).unwrap();
+            }
+
+            let (worst, worst_value) = (simplex[n].0.clone(), simplex[n].1);
+            let second_worst_value = simplex[n - 1].1;
+
+            let reflected = point(&centroid, &worst, T::one());
+            let reflected_value = evaluate(&reflected);
+
+            if reflected_value < best_value {
+                let expanded = point(&centroid, &worst, two);
+                let expanded_value = evaluate(&expanded);
+                simplex[n] = if expanded_value < reflected_value {
+                    (expanded, expanded_value)
+                } else {
+                    (reflected, reflected_value)
+                };
+                continue;
+            }
+            if reflected_value < second_worst_value {
+                simplex[n] = (reflected, reflected_value);
+                continue;
+            }
+
+            // Contract towards the better of the reflected and worst points
+            let (scale, threshold) = if reflected_value < worst_value {
+                (half, reflected_value)
+            } else {
+                (-half, worst_value)
+            };
+            let contracted = point(&centroid, &worst, scale);
+            let contracted_value = evaluate(&contracted);
+            if contracted_value <= threshold {
+                simplex[n] = (contracted, contracted_value);
+                continue;
+            }
+
+            // Shrink every vertex towards the best
+            let best = simplex[0].0.clone();
+            for (vertex, value) in &mut simplex[1..] {
+                for (x, &b) in vertex.iter_mut().zip(&best) {
+                    *x = b + half * (*x - b);
+                }
+                *value = evaluate(vertex);
+            }
+        }
+
+        let (point, value) = simplex
+            .into_iter()
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal))
+            .unwrap();
+        Minimum {
+            point,
+            value,
+            iterations,
+            evaluations,
+            status,
+        }
+    }
+}
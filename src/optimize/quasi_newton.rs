@@ -0,0 +1,242 @@
+use super::{
+    dot,
+    line_search::{descend, Direction},
+    Gradient, Minimum,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use num::{traits::real::Real, FromPrimitive};
+
+/// Configuration for [`Minimize::bfgs`](super::Minimize::bfgs).
+///
+/// The search stops once the largest component of the gradient is within `gradient_tolerance`,
+/// the function decreases by a relative amount within `function_tolerance` in an iteration,
+/// or after `max_iterations`.
+pub struct Bfgs<T> {
+    pub gradient_tolerance: T,
+    pub function_tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for Bfgs<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            gradient_tolerance: T::epsilon().cbrt(),
+            function_tolerance: T::epsilon(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl<T> Bfgs<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn minimize<F, G>(&self, start: &[T], f: &mut F, gradient: &mut G) -> Minimum<T>
+    where
+        F: FnMut(&[T]) -> T,
+        G: Gradient<T>,
+    {
+        descend(
+            start,
+            f,
+            gradient,
+            InverseHessian::new(start.len()),
+            self.gradient_tolerance,
+            self.function_tolerance,
+            self.max_iterations,
+        )
+    }
+}
+
+/// Dense approximation of the inverse Hessian, starting from the identity.
+struct InverseHessian<T> {
+    matrix: Vec<T>,
+    n: usize,
+    /// Whether the matrix is still the unscaled identity.
+    initial: bool,
+}
+
+impl<T: Real> InverseHessian<T> {
+    fn new(n: usize) -> Self {
+        let mut hessian = Self {
+            matrix: alloc::vec![T::zero(); n * n],
+            n,
+            initial: true,
+        };
+        hessian.identity(T::one());
+        hessian
+    }
+
+    fn identity(&mut self, scale: T) {
+        for (i, h) in self.matrix.iter_mut().enumerate() {
+            *h = if i / self.n == i % self.n {
+                scale
+            } else {
+                T::zero()
+            };
+        }
+    }
+}
+
+impl<T: Real> Direction<T> for InverseHessian<T> {
+    fn direction(&mut self, gradient: &[T], direction: &mut [T]) {
+        for (d, row) in direction.iter_mut().zip(self.matrix.chunks(self.n)) {
+            *d = -dot(row, gradient);
+        }
+    }
+
+    fn update(&mut self, step: &[T], change: &[T]) {
+        let curvature = dot(step, change);
+        // Skip updates that would lose positive definiteness
+        if curvature <= T::epsilon() * dot(step, step).sqrt() * dot(change, change).sqrt() {
+            return;
+        }
+
+        // Scale the identity to the curvature along the first step
+        if self.initial {
+            self.identity(curvature / dot(change, change));
+            self.initial = false;
+        }
+
+        let rho = curvature.recip();
+        let hy: Vec<T> = self
+            .matrix
+            .chunks(self.n)
+            .map(|row| dot(row, change))
+            .collect();
+        let factor = (rho * dot(change, &hy) + T::one()) * rho;
+        for (i, row) in self.matrix.chunks_mut(self.n).enumerate() {
+            for (j, h) in row.iter_mut().enumerate() {
+                *h = *h - rho * (step[i] * hy[j] + hy[i] * step[j]) + factor * step[i] * step[j];
+            }
+        }
+    }
+
+    fn reset(&mut self) -> bool {
+        if self.initial {
+            return false;
+        }
+        self.identity(T::one());
+        self.initial = true;
+        true
+    }
+}
+
+/// Configuration for [`Minimize::lbfgs`](super::Minimize::lbfgs).
+///
+/// The last `memory` steps and gradient changes approximate the inverse Hessian.
+/// The search stops once the largest component of the gradient is within `gradient_tolerance`,
+/// the function decreases by a relative amount within `function_tolerance` in an iteration,
+/// or after `max_iterations`.
+pub struct Lbfgs<T> {
+    pub memory: usize,
+    pub gradient_tolerance: T,
+    pub function_tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for Lbfgs<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            memory: 10,
+            gradient_tolerance: T::epsilon().cbrt(),
+            function_tolerance: T::epsilon(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl<T> Lbfgs<T>
+where
+    T: Real + FromPrimitive,
+{
+    pub(crate) fn minimize<F, G>(&self, start: &[T], f: &mut F, gradient: &mut G) -> Minimum<T>
+    where
+        F: FnMut(&[T]) -> T,
+        G: Gradient<T>,
+    {
+        descend(
+            start,
+            f,
+            gradient,
+            History {
+                memory: self.memory,
+                pairs: VecDeque::with_capacity(self.memory),
+                alphas: Vec::with_capacity(self.memory),
+            },
+            self.gradient_tolerance,
+            self.function_tolerance,
+            self.max_iterations,
+        )
+    }
+}
+
+/// Recent steps and gradient changes, along with the reciprocal of their curvature.
+struct History<T> {
+    memory: usize,
+    pairs: VecDeque<(Vec<T>, Vec<T>, T)>,
+    alphas: Vec<T>,
+}
+
+impl<T: Real> Direction<T> for History<T> {
+    /// Apply the inverse Hessian approximation with the two-loop recursion.
+    fn direction(&mut self, gradient: &[T], direction: &mut [T]) {
+        for (d, &g) in direction.iter_mut().zip(gradient) {
+            *d = g;
+        }
+
+        self.alphas.clear();
+        for (step, change, rho) in self.pairs.iter().rev() {
+            let alpha = *rho * dot(step, direction);
+            for (d, &y) in direction.iter_mut().zip(change) {
+                *d = *d - alpha * y;
+            }
+            self.alphas.push(alpha);
+        }
+
+        if let Some((step, change, _)) = self.pairs.back() {
+            let scale = dot(step, change) / dot(change, change);
+            for d in direction.iter_mut() {
+                *d = *d * scale;
+            }
+        }
+
+        for ((step, change, rho), &alpha) in self.pairs.iter().zip(self.alphas.iter().rev()) {
+            let beta = *rho * dot(change, direction);
+            for (d, &s) in direction.iter_mut().zip(step) {
+                *d = *d + (alpha - beta) * s;
+            }
+        }
+
+        for d in direction.iter_mut() {
+            *d = -*d;
+        }
+    }
+
+    fn update(&mut self, step: &[T], change: &[T]) {
+        let curvature = dot(step, change);
+        if self.memory == 0
+            || curvature <= T::epsilon() * dot(step, step).sqrt() * dot(change, change).sqrt()
+        {
+            return;
+        }
+
+        if self.pairs.len() == self.memory {
+            self.pairs.pop_front();
+        }
+        self.pairs
+            .push_back((step.to_vec(), change.to_vec(), curvature.recip()));
+    }
+
+    fn reset(&mut self) -> bool {
+        let reset = !self.pairs.is_empty();
+        self.pairs.clear();
+        reset
+    }
+}
@@ -0,0 +1,303 @@
+//! Minimization of functions of one variable
+
+use super::Status;
+use crate::roots::Termination;
+use num::{traits::real::Real, FromPrimitive};
+
+/// Tolerance at `x` from `termination`, which is no tighter than the square root of epsilon relative to `x`,
+/// since a function is flat to second order around its minimum.
+fn tolerance<T: Real>(termination: &Termination<T>, x: T) -> T {
+    termination.tolerance(x).max(T::epsilon().sqrt() * x.abs())
+}
+
+/// Approximate minimum of a function of one variable.
+#[derive(Clone, Copy, Debug)]
+pub struct ScalarMinimum<T> {
+    pub point: T,
+    /// Value of the function at `point`.
+    pub value: T,
+    /// Bound on the distance from `point` to the minimum, from the final interval.
+    pub error: T,
+    pub iterations: usize,
+    pub status: Status,
+}
+
+impl<T> ScalarMinimum<T> {
+    pub fn converged(&self) -> bool {
+        self.status == Status::IntervalTolerance
+    }
+}
+
+/// Three points with the middle one below the outer two, so a continuous function
+/// has a minimum between the outer points. Each point is stored along with its function value.
+#[derive(Clone, Copy, Debug)]
+pub struct MinimumBracket<T> {
+    pub lower: (T, T),
+    pub middle: (T, T),
+    pub upper: (T, T),
+}
+
+/// Bracket a minimum by walking downhill from `a` towards `b` in steps growing by the golden ratio,
+/// returning `None` if no bracket is found within `max_iterations` steps.
+/// The middle point must be strictly below the outer two, so a flat function has no bracket.
+///
+/// ```
+/// use quickmaths::optimize::scalar::bracket;
+///
+/// let found = bracket(0., 1., |x: f64| (x - 10.).powi(2), 20).unwrap();
+/// assert!(found.lower.0 < 10. && 10. < found.upper.0);
+/// assert!(found.middle.1 < found.lower.1 && found.middle.1 < found.upper.1);
+///
+/// // Unbounded below
+/// assert!(bracket(0., 1., |x: f64| -x, 20).is_none());
+/// assert!(bracket(0., 1., |_: f64| 1., 20).is_none());
+/// ```
+pub fn bracket<F, T>(a: T, b: T, mut f: F, max_iterations: usize) -> Option<MinimumBracket<T>>
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+{
+    let golden = T::from_f64(1.618033988749895).unwrap();
+
+    let (mut a, mut b) = ((a, f(a)), (b, f(b)));
+    if b.1 > a.1 {
+        core::mem::swap(&mut a, &mut b);
+    }
+
+    let step = |a: T, b: T| b + golden * (b - a);
+    let x = step(a.0, b.0);
+    let mut c = (x, f(x));
+    for _ in 0..max_iterations {
+        if c.1 > b.1 && b.1 < a.1 {
+            let (lower, upper) = if a.0 < c.0 { (a, c) } else { (c, a) };
+            return Some(MinimumBracket {
+                lower,
+                middle: b,
+                upper,
+            });
+        }
+
+        a = b;
+        b = c;
+        let x = step(a.0, b.0);
+        c = (x, f(x));
+    }
+
+    None
+}
+
+/// Minimum of function `f` between `start` and `end`.
+///
+/// The search stops according to a [`Termination`], whose relative tolerance is raised to at least
+/// the square root of epsilon, since the minimum can't be located more precisely than that.
+pub struct Interval<F, T> {
+    start: T,
+    end: T,
+    f: F,
+}
+
+impl<F, T> Interval<F, T> {
+    pub fn new(start: T, end: T, f: F) -> Self {
+        Self { start, end, f }
+    }
+}
+
+impl<F, T> Interval<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+{
+    /// Find the minimum with [golden-section search](https://en.wikipedia.org/wiki/Golden-section_search),
+    /// shrinking the interval by the golden ratio each iteration.
+    /// The function must be unimodal on the interval.
+    ///
+    /// ```
+    /// use quickmaths::optimize::scalar::Interval;
+    /// use quickmaths::roots::Termination;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let minimum = Interval::new(0., 2., |x: f64| x.cos() + x * x / 4.)
+    ///     .golden_section(Termination::default());
+    /// assert!(minimum.converged());
+    /// assert_relative_eq!(minimum.point, 1.8954942670339812, epsilon = 1e-7);
+    /// ```
+    pub fn golden_section(&mut self, termination: Termination<T>) -> ScalarMinimum<T> {
+        let ratio = T::from_f64(0.6180339887498949).unwrap();
+        let two = T::from_u8(2).unwrap();
+
+        let (mut a, mut b) = self.ordered();
+        let mut x1 = b - ratio * (b - a);
+        let mut x2 = a + ratio * (b - a);
+        let (mut f1, mut f2) = ((self.f)(x1), (self.f)(x2));
+
+        for iterations in 1..=termination.max_iterations {
+            let (point, value) = if f1 < f2 { (x1, f1) } else { (x2, f2) };
+            let error = (b - a) / two;
+            if error <= tolerance(&termination, point) {
+                return ScalarMinimum {
+                    point,
+                    value,
+                    error,
+                    iterations: iterations - 1,
+                    status: Status::IntervalTolerance,
+                };
+            }
+
+            if f1 < f2 {
+                b = x2;
+                x2 = x1;
+                f2 = f1;
+                x1 = b - ratio * (b - a);
+                f1 = (self.f)(x1);
+            } else {
+                a = x1;
+                x1 = x2;
+                f1 = f2;
+                x2 = a + ratio * (b - a);
+                f2 = (self.f)(x2);
+            }
+        }
+
+        let (point, value) = if f1 < f2 { (x1, f1) } else { (x2, f2) };
+        ScalarMinimum {
+            point,
+            value,
+            error: (b - a) / two,
+            iterations: termination.max_iterations,
+            status: Status::MaxIterations,
+        }
+    }
+
+    /// Find the minimum with [Brent's method](https://en.wikipedia.org/wiki/Brent%27s_method#Brent's_minimization_method),
+    /// combining parabolic interpolation through the best three points with golden-section steps
+    /// as a fallback.
+    ///
+    /// ```
+    /// use quickmaths::optimize::scalar::{bracket, Interval};
+    /// use quickmaths::roots::Termination;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let f = |x: f64| x.cos() + x * x / 4.;
+    /// let minimum = Interval::new(0., 2., f).brent(Termination::default());
+    /// assert!(minimum.converged());
+    /// assert_relative_eq!(minimum.point, 1.8954942670339812, epsilon = 1e-7);
+    /// assert!(minimum.iterations < 15);
+    ///
+    /// // Search between the outer points of a bracket
+    /// let found = bracket(0., 0.1, |x: f64| x.exp() - 5. * x, 50).unwrap();
+    /// let minimum = Interval::new(found.lower.0, found.upper.0, |x: f64| x.exp() - 5. * x)
+    ///     .brent(Termination::default());
+    /// assert_relative_eq!(minimum.point, 5f64.ln(), epsilon = 1e-7);
+    /// ```
+    pub fn brent(&mut self, termination: Termination<T>) -> ScalarMinimum<T> {
+        let golden = T::from_f64(0.3819660112501051).unwrap();
+        let two = T::from_u8(2).unwrap();
+
+        let (mut a, mut b) = self.ordered();
+        // Best point so far, second best and previous second best
+        let mut x = a + golden * (b - a);
+        let (mut w, mut v) = (x, x);
+        let mut fx = (self.f)(x);
+        let (mut fw, mut fv) = (fx, fx);
+        // Last step and the one before it
+        let (mut d, mut e) = (T::zero(), T::zero());
+
+        for iterations in 1..=termination.max_iterations {
+            let m = (a + b) / two;
+            let tolerance = tolerance(&termination, x);
+            if (x - m).abs() <= two * tolerance - (b - a) / two {
+                return ScalarMinimum {
+                    point: x,
+                    value: fx,
+                    error: (b - a) / two,
+                    iterations: iterations - 1,
+                    status: Status::IntervalTolerance,
+                };
+            }
+
+            let mut golden_step = true;
+            if e.abs() > tolerance {
+                // Parabola through x, w and v
+                let r = (x - w) * (fx - fv);
+                let mut q = (x - v) * (fx - fw);
+                let mut p = (x - v) * q - (x - w) * r;
+                q = two * (q - r);
+                if q > T::zero() {
+                    p = -p;
+                } else {
+                    q = -q;
+                }
+
+                let previous = e;
+                e = d;
+                if p.abs() < (q * previous / two).abs() && p > q * (a - x) && p < q * (b - x) {
+                    d = p / q;
+                    let u = x + d;
+                    // Don't evaluate too close to the ends
+                    if u - a < two * tolerance || b - u < two * tolerance {
+                        d = if x < m { tolerance } else { -tolerance };
+                    }
+                    golden_step = false;
+                }
+            }
+            if golden_step {
+                e = if x < m { b - x } else { a - x };
+                d = golden * e;
+            }
+
+            let u = if d.abs() >= tolerance {
+                x + d
+            } else if d > T::zero() {
+                x + tolerance
+            } else {
+                x - tolerance
+            };
+            let fu = (self.f)(u);
+
+            if fu <= fx {
+                if u < x {
+                    b = x;
+                } else {
+                    a = x;
+                }
+                v = w;
+                fv = fw;
+                w = x;
+                fw = fx;
+                x = u;
+                fx = fu;
+            } else {
+                if u < x {
+                    a = u;
+                } else {
+                    b = u;
+                }
+                if fu <= fw || w == x {
+                    v = w;
+                    fv = fw;
+                    w = u;
+                    fw = fu;
+                } else if fu <= fv || v == x || v == w {
+                    v = u;
+                    fv = fu;
+                }
+            }
+        }
+
+        ScalarMinimum {
+            point: x,
+            value: fx,
+            error: (b - a) / two,
+            iterations: termination.max_iterations,
+            status: Status::MaxIterations,
+        }
+    }
+
+    fn ordered(&self) -> (T, T) {
+        if self.start <= self.end {
+            (self.start, self.end)
+        } else {
+            (self.end, self.start)
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use super::{Derivative, Divide, Integral};
+use alloc::vec::Vec;
+use core::{
+    ops::{Add, Mul, Neg, Sub},
+    slice,
+};
+use num::{One, Zero};
+
+/// Polynomial with owned coefficients in ascending order of degree.
+///
+/// The coefficients are normalized without trailing zeros, so the zero polynomial has none.
+/// A reference to a `Poly` iterates over its coefficients, so it also implements [`Polynomial`](super::Polynomial).
+///
+/// ```
+/// use quickmaths::poly::{Poly, Polynomial};
+///
+/// // (1 + x)(1 - x) = 1 - x^2
+/// let a = Poly::new([1, 1]);
+/// let b = Poly::new([1, -1]);
+/// let product = &a * &b;
+///
+/// assert_eq!(product.coefficients(), [1, 0, -1]);
+/// assert_eq!(product.degree(), Some(2));
+/// assert_eq!(product.eval(3), -8);
+///
+/// // Adding cancels the leading coefficient
+/// assert_eq!(a + b, Poly::new([2]));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Poly<T> {
+    coefficients: Vec<T>,
+}
+
+impl<T: Zero> Poly<T> {
+    pub fn new<I>(coefficients: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut poly = Self {
+            coefficients: coefficients.into_iter().collect(),
+        };
+        poly.normalize();
+        poly
+    }
+
+    /// Remove trailing zero coefficients.
+    fn normalize(&mut self) {
+        while self.coefficients.last().is_some_and(Zero::is_zero) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl<T> Poly<T> {
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    pub fn into_coefficients(self) -> Vec<T> {
+        self.coefficients
+    }
+
+    /// Degree of the polynomial, or `None` for the zero polynomial.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// assert_eq!(Poly::new([3., 0., 2., 0.]).degree(), Some(2));
+    /// assert_eq!(Poly::new([0.]).degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Coefficient of the highest degree term, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&T> {
+        self.coefficients.last()
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.coefficients.iter()
+    }
+}
+
+impl<T: Zero> FromIterator<T> for Poly<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::new(iter)
+    }
+}
+
+impl<T: Zero> From<Vec<T>> for Poly<T> {
+    fn from(coefficients: Vec<T>) -> Self {
+        Self::new(coefficients)
+    }
+}
+
+/// ```
+/// use quickmaths::poly::{Poly, Polynomial};
+///
+/// let poly = Poly::new([0., 4.]);
+/// assert_eq!(Poly::from(poly.integral()), Poly::new([0., 0., 2.]));
+/// ```
+impl<I> From<Integral<I>> for Poly<I::Item>
+where
+    I: Iterator,
+    Integral<I>: Iterator<Item = I::Item>,
+    I::Item: Zero,
+{
+    fn from(integral: Integral<I>) -> Self {
+        Self::new(integral)
+    }
+}
+
+/// ```
+/// use quickmaths::poly::{Poly, Polynomial};
+///
+/// let poly = Poly::new([-20, 30, 0, -12]);
+/// assert_eq!(Poly::from(poly.primitive_part()), Poly::new([-10, 15, 0, -6]));
+/// ```
+impl<I, U> From<Divide<I, U>> for Poly<I::Item>
+where
+    I: Iterator,
+    Divide<I, U>: Iterator<Item = I::Item>,
+    I::Item: Zero,
+{
+    fn from(divide: Divide<I, U>) -> Self {
+        Self::new(divide)
+    }
+}
+
+/// ```
+/// use quickmaths::poly::{Poly, Polynomial};
+///
+/// let poly = Poly::new([5, 3, 0, 2]);
+/// assert_eq!(Poly::from(poly.derivative()), Poly::new([3, 0, 6]));
+/// ```
+impl<I> From<Derivative<I>> for Poly<I::Item>
+where
+    I: Iterator,
+    Derivative<I>: Iterator<Item = I::Item>,
+    I::Item: Zero,
+{
+    fn from(derivative: Derivative<I>) -> Self {
+        Self::new(derivative)
+    }
+}
+
+impl<T> IntoIterator for Poly<T> {
+    type Item = T;
+
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coefficients.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Poly<T> {
+    type Item = &'a T;
+
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.coefficients.iter()
+    }
+}
+
+impl<T> Add for &Poly<T>
+where
+    T: Zero + Clone,
+{
+    type Output = Poly<T>;
+
+    fn add(self, rhs: Self) -> Poly<T> {
+        let (long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut coefficients = long.coefficients.clone();
+        for (c, b) in coefficients.iter_mut().zip(&short.coefficients) {
+            *c = c.clone() + b.clone();
+        }
+        Poly::new(coefficients)
+    }
+}
+
+impl<T> Sub for &Poly<T>
+where
+    T: Zero + Clone + Sub<Output = T>,
+{
+    type Output = Poly<T>;
+
+    fn sub(self, rhs: Self) -> Poly<T> {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Poly::new((0..len).map(|i| {
+            let a = self.coefficients.get(i).cloned().unwrap_or_else(T::zero);
+            let b = rhs.coefficients.get(i).cloned().unwrap_or_else(T::zero);
+            a - b
+        }))
+    }
+}
+
+impl<T> Mul for &Poly<T>
+where
+    T: Zero + Clone + Mul<Output = T>,
+{
+    type Output = Poly<T>;
+
+    fn mul(self, rhs: Self) -> Poly<T> {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return Poly::zero();
+        }
+
+        let mut coefficients =
+            alloc::vec![T::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Poly::new(coefficients)
+    }
+}
+
+impl<T> Neg for &Poly<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    type Output = Poly<T>;
+
+    fn neg(self) -> Poly<T> {
+        Poly {
+            coefficients: self.coefficients.iter().cloned().map(Neg::neg).collect(),
+        }
+    }
+}
+
+impl<T> Neg for Poly<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            coefficients: self.coefficients.into_iter().map(Neg::neg).collect(),
+        }
+    }
+}
+
+macro_rules! forward_owned_binop {
+    ($($imp:ident $method:ident [$($bound:tt)*]),*) => {
+        $(
+            impl<T> $imp for Poly<T>
+            where
+                T: Zero + Clone $($bound)*,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    (&self).$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned_binop!(
+    Add add [],
+    Sub sub [+ Sub<Output = T>],
+    Mul mul [+ Mul<Output = T>]
+);
+
+impl<T> Zero for Poly<T>
+where
+    T: Zero + Clone,
+{
+    fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<T> One for Poly<T>
+where
+    T: Zero + One + Clone,
+{
+    fn one() -> Self {
+        Self {
+            coefficients: alloc::vec![T::one()],
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use super::{Poly, Polynomial};
+use core::ops::{Div, Mul, Sub};
+use num::{pow, rational::Ratio, Complex, Float, Integer, Num, One, Zero};
+
+/// Coefficients where every nonzero value can be divided by exactly, up to rounding for floats.
+///
+/// Integers are not fields, since their division truncates. Integer polynomials can use
+/// [`Poly::pseudo_div_rem`] and [`Poly::subresultant_gcd`] instead.
+pub trait Field: Num + Clone {}
+
+impl Field for f32 {}
+
+impl Field for f64 {}
+
+impl<T: Clone + Integer> Field for Ratio<T> {}
+
+impl<T: Clone + Float> Field for Complex<T> {}
+
+/// Division over a field such as floats or rationals, where every nonzero coefficient can be divided by.
+impl<T> Poly<T>
+where
+    T: Field,
+{
+    /// Quotient and remainder of [polynomial long division](https://en.wikipedia.org/wiki/Polynomial_long_division)
+    /// by `divisor`, where the remainder has a lower degree than the divisor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// // x^3 - 2x^2 - 4 = (x - 3)(x^2 + x + 3) + 5
+    /// let (quotient, remainder) = Poly::new([-4., 0., -2., 1.]).div_rem(&Poly::new([-3., 1.]));
+    /// assert_eq!(quotient, Poly::new([3., 1., 1.]));
+    /// assert_eq!(remainder, Poly::new([5.]));
+    /// ```
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let lead = divisor
+            .leading_coefficient()
+            .expect("division by the zero polynomial")
+            .clone();
+        let n = divisor.coefficients().len();
+        if self.coefficients().len() < n {
+            return (Self::zero(), self.clone());
+        }
+
+        let mut remainder = self.coefficients().to_vec();
+        let mut quotient = alloc::vec![T::zero(); remainder.len() + 1 - n];
+        for i in (0..quotient.len()).rev() {
+            let q = remainder[i + n - 1].clone() / lead.clone();
+            for (j, d) in divisor.iter().enumerate() {
+                remainder[i + j] = remainder[i + j].clone() - q.clone() * d.clone();
+            }
+            quotient[i] = q;
+        }
+        // The eliminated coefficients are only approximately zero for floats
+        remainder.truncate(n - 1);
+
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Monic [greatest common divisor](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor)
+    /// with the Euclidean algorithm, or zero if both polynomials are zero.
+    ///
+    /// With floats, remainders that should vanish may only be close to zero, so this is best used with exact
+    /// coefficients like [`Ratio`](num::rational::Ratio).
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// // (x - 1)(x - 2) and (x - 1)(x + 3)
+    /// let a = Poly::new([2., -3., 1.]);
+    /// let b = Poly::new([-3., 2., 1.]);
+    /// assert_eq!(a.gcd(&b), Poly::new([-1., 1.]));
+    /// ```
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = a.div_rem(&b).1;
+            a = b;
+            b = remainder;
+        }
+        a.monic().0
+    }
+
+    /// Monic greatest common divisor `g` with [Bézout](https://en.wikipedia.org/wiki/B%C3%A9zout%27s_identity)
+    /// cofactors `s` and `t` such that `s * self + t * other = g`, from the extended Euclidean algorithm.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    /// use num::rational::Ratio;
+    ///
+    /// let a = Poly::new([2, -3, 1].map(Ratio::from_integer));
+    /// let b = Poly::new([-3, 2, 1].map(Ratio::from_integer));
+    /// let (g, s, t) = a.extended_gcd(&b);
+    ///
+    /// assert_eq!(g, Poly::new([-1, 1].map(Ratio::from_integer)));
+    /// assert_eq!(&s * &a + &t * &b, g);
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::one(), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::one());
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            let s = &s0 - &(&quotient * &s1);
+            let t = &t0 - &(&quotient * &t1);
+
+            r0 = core::mem::replace(&mut r1, remainder);
+            s0 = core::mem::replace(&mut s1, s);
+            t0 = core::mem::replace(&mut t1, t);
+        }
+
+        let (g, lead) = r0.monic();
+        let scale = |p: Self| Self::new(p.into_iter().map(|c| c / lead.clone()));
+        (g, scale(s0), scale(t0))
+    }
+
+    /// Divide by the leading coefficient, also returning it.
+    fn monic(self) -> (Self, T) {
+        match self.leading_coefficient().cloned() {
+            Some(lead) => (Self::new(self.into_iter().map(|c| c / lead.clone())), lead),
+            None => (self, T::one()),
+        }
+    }
+}
+
+impl<T> Poly<T>
+where
+    T: Zero + One + Clone + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Quotient `q` and remainder `r` of [pseudo-division](https://en.wikipedia.org/wiki/Polynomial_long_division#Pseudo-division)
+    /// by `divisor`, which avoids dividing coefficients by scaling with its leading coefficient `l`
+    /// so that `l^(m - n + 1) * self = q * divisor + r` for degrees `m` and `n`, and `r` has degree less than `n`.
+    ///
+    /// When the degree of `self` is less than `n`, the quotient is zero and the remainder is `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// let a = Poly::new([5, 1, 1, 3]);
+    /// let b = Poly::new([1, -3, 5]);
+    /// let (q, r) = a.pseudo_div_rem(&b);
+    ///
+    /// assert_eq!(r.degree(), Some(1));
+    /// assert_eq!(&Poly::new([25]) * &a, &q * &b + r);
+    /// ```
+    pub fn pseudo_div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let n = divisor
+            .degree()
+            .expect("pseudo-division by the zero polynomial");
+        let m = match self.degree() {
+            Some(m) if m >= n => m,
+            _ => return (Self::zero(), self.clone()),
+        };
+        let b = divisor.coefficients();
+        let lead = b[n].clone();
+
+        // Knuth's algorithm R, scaling the whole dividend by the leading coefficient at each step
+        let mut u = self.coefficients().to_vec();
+        let mut quotient = alloc::vec![T::zero(); m - n + 1];
+        for k in (0..=m - n).rev() {
+            let top = u[n + k].clone();
+            quotient[k] = top.clone() * pow(lead.clone(), k);
+            for j in (0..n + k).rev() {
+                u[j] = lead.clone() * u[j].clone();
+                if j >= k {
+                    u[j] = u[j].clone() - top.clone() * b[j - k].clone();
+                }
+            }
+        }
+        u.truncate(n);
+
+        (Self::new(quotient), Self::new(u))
+    }
+
+    /// Remainder of [`pseudo_div_rem`](Self::pseudo_div_rem).
+    pub fn pseudo_rem(&self, divisor: &Self) -> Self {
+        self.pseudo_div_rem(divisor).1
+    }
+}
+
+/// Greatest common divisors over the integers, normalized to a positive leading coefficient.
+impl<T> Poly<T>
+where
+    T: Integer + Clone,
+    for<'a> T: Div<&'a T, Output = T>,
+{
+    /// Greatest common divisor with the
+    /// [subresultant pseudo-remainder sequence](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor#Subresultant_pseudo-remainder_sequence),
+    /// which divides out known factors of each pseudo-remainder to keep coefficient growth polynomial
+    /// without computing contents.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// // 6(2x + 1)(x - 3) and 4(2x + 1)(x + 5)
+    /// let a = Poly::new([-18, -30, 12]);
+    /// let b = Poly::new([20, 44, 8]);
+    /// assert_eq!(a.subresultant_gcd(&b), Poly::new([2, 4]));
+    ///
+    /// // Knuth's example of coefficient growth in the Euclidean algorithm
+    /// let a = Poly::new([-5i64, 2, 8, -3, -3, 0, 1, 0, 1]);
+    /// let b = Poly::new([21, -9, -4, 0, 5, 0, 3]);
+    /// assert_eq!(a.subresultant_gcd(&b), Poly::new([1]));
+    /// ```
+    pub fn subresultant_gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = match (self.degree(), other.degree()) {
+            (_, None) => return self.clone().normalize_sign(),
+            (None, _) => return other.clone().normalize_sign(),
+            (Some(m), Some(n)) if m < n => (other.clone(), self.clone()),
+            _ => (self.clone(), other.clone()),
+        };
+
+        let content = a.clone().content().gcd(&b.clone().content());
+        a = Self::from(a.primitive_part());
+        b = Self::from(b.primitive_part());
+
+        let (mut g, mut h) = (T::one(), T::one());
+        loop {
+            let delta = a.coefficients().len() - b.coefficients().len();
+            let remainder = a.pseudo_rem(&b);
+            match remainder.degree() {
+                None => break,
+                Some(0) => {
+                    b = Self::one();
+                    break;
+                }
+                Some(_) => {}
+            }
+
+            // The subresultant factors divide the pseudo-remainder exactly
+            let divisor = g.clone() * pow(h.clone(), delta);
+            a = b;
+            b = Self::from(remainder.div(divisor));
+            g = a.leading_coefficient().unwrap().clone();
+            if delta > 0 {
+                h = pow(g.clone(), delta) / &pow(h, delta - 1);
+            }
+        }
+
+        (Self::new([content]) * Self::from(b.primitive_part())).normalize_sign()
+    }
+
+    /// Greatest common divisor with the primitive pseudo-remainder sequence, which divides each pseudo-remainder
+    /// by its [content](super::Polynomial::content) to keep the coefficients as small as possible.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// let a = Poly::new([-18, -30, 12]);
+    /// let b = Poly::new([20, 44, 8]);
+    /// assert_eq!(a.primitive_gcd(&b), Poly::new([2, 4]));
+    /// assert_eq!(a.primitive_gcd(&Poly::new([0])), a);
+    /// ```
+    pub fn primitive_gcd(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return (self + other).normalize_sign();
+        }
+
+        let content = self.clone().content().gcd(&other.clone().content());
+        let mut a = Self::from(self.clone().primitive_part());
+        let mut b = Self::from(other.clone().primitive_part());
+        while !b.is_zero() {
+            let remainder = a.pseudo_rem(&b);
+            a = b;
+            b = Self::from(remainder.primitive_part());
+        }
+
+        (Self::new([content]) * a).normalize_sign()
+    }
+
+    fn normalize_sign(self) -> Self {
+        match self.leading_coefficient() {
+            Some(lead) if *lead < T::zero() => Self::new(self.into_iter().map(|c| T::zero() - c)),
+            _ => self,
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use super::{Poly, Polynomial};
+use alloc::vec::Vec;
+use core::ops::Div;
+use num::{pow, rational::Ratio, FromPrimitive, Integer, Signed, Zero};
+
+/// [Sturm sequence](https://en.wikipedia.org/wiki/Sturm%27s_theorem) of a polynomial,
+/// which counts its distinct real roots in an interval exactly.
+///
+/// ```
+/// use quickmaths::poly::Poly;
+/// use num::rational::Ratio;
+///
+/// // x^3 - 2x has roots at -√2, 0 and √2
+/// let sturm = Poly::new([0, -2, 0, 1]).sturm();
+/// assert_eq!(sturm.count_real(), 3);
+///
+/// // The interval excludes its lower end
+/// assert_eq!(sturm.count(&Ratio::from_integer(0), &Ratio::from_integer(2)), 1);
+/// assert_eq!(sturm.count(&Ratio::new(-1, 2), &Ratio::new(3, 2)), 2);
+///
+/// // Repeated roots are counted once: (x - 1)^2 (x + 2)
+/// let sturm = Poly::new([2, -3, 0, 1]).sturm();
+/// assert_eq!(sturm.count_real(), 2);
+/// assert_eq!(sturm.count(&Ratio::from_integer(0), &Ratio::from_integer(1)), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sturm<T> {
+    sequence: Vec<Poly<T>>,
+}
+
+impl<T> Sturm<T>
+where
+    T: Integer + Signed + Clone,
+{
+    pub fn sequence(&self) -> &[Poly<T>] {
+        &self.sequence
+    }
+
+    /// Number of sign changes in the sequence evaluated at `x`, ignoring zeros.
+    pub fn sign_changes(&self, x: &Ratio<T>) -> usize {
+        sign_variations(self.sequence.iter().map(|p| sign_at(p, x)))
+    }
+
+    /// Number of distinct real roots in the half-open interval `(lower, upper]`.
+    pub fn count(&self, lower: &Ratio<T>, upper: &Ratio<T>) -> usize {
+        self.sign_changes(lower)
+            .saturating_sub(self.sign_changes(upper))
+    }
+
+    /// Number of distinct real roots, from the signs of the sequence at negative and positive infinity.
+    pub fn count_real(&self) -> usize {
+        let at_infinity = |negative: bool| {
+            sign_variations(self.sequence.iter().map(|p| {
+                let lead = p.leading_coefficient().unwrap().signum();
+                let odd = p.degree().unwrap() % 2 == 1;
+                if negative && odd {
+                    -lead
+                } else {
+                    lead
+                }
+            }))
+        };
+        at_infinity(true).saturating_sub(at_infinity(false))
+    }
+}
+
+/// Open interval `(lower, upper)` containing exactly one real root, or the root itself when `lower == upper`.
+#[derive(Clone, Debug)]
+pub struct Interval<T> {
+    pub lower: Ratio<T>,
+    pub upper: Ratio<T>,
+}
+
+impl<T: Clone + Integer> PartialEq for Interval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.lower == other.lower && self.upper == other.upper
+    }
+}
+
+impl<T: Clone + Integer> Eq for Interval<T> {}
+
+impl<T: Clone + Integer> Interval<T> {
+    /// Whether the interval is a single exact root.
+    pub fn is_exact(&self) -> bool {
+        self.lower == self.upper
+    }
+}
+
+/// Exact real root counting and isolation over the integers.
+///
+/// The coefficients can grow quickly, so large or high degree polynomials may need
+/// arbitrary precision integers.
+impl<T> Poly<T>
+where
+    T: Integer + Signed + Clone + FromPrimitive,
+    for<'a> T: Div<&'a T, Output = T>,
+{
+    /// Sturm sequence starting from the polynomial and its derivative, where each following term is the
+    /// negated remainder of the previous two, computed with pseudo-division and divided by its content.
+    ///
+    /// When there are repeated roots, the last term is the greatest common divisor of the polynomial and its
+    /// derivative, and every term is divided by it so the bounds of [`Sturm::count`] can also be roots.
+    pub fn sturm(&self) -> Sturm<T> {
+        let mut sequence = Vec::new();
+        if self.is_zero() {
+            return Sturm { sequence };
+        }
+        sequence.push(self.clone());
+
+        let mut next = Self::from(self.clone().derivative());
+        while !next.is_zero() {
+            let previous = sequence.last().unwrap();
+            let remainder = previous.pseudo_rem(&next);
+
+            // Pseudo-division scales the remainder by the leading coefficient to the power of this exponent,
+            // so undo a negative factor to keep the signs of the true remainder
+            let exponent = previous.coefficients().len() - next.coefficients().len() + 1;
+            let negative = next.leading_coefficient().unwrap().is_negative() && exponent % 2 == 1;
+            let remainder = if negative { remainder } else { -remainder };
+
+            sequence.push(next);
+            next = reduce(remainder);
+        }
+
+        let gcd = sequence.last().unwrap().clone();
+        if gcd.degree() != Some(0) {
+            // Dividing every term by the same factor keeps the sign changes between them
+            let gcd = if gcd.leading_coefficient().unwrap().is_negative() {
+                -gcd
+            } else {
+                gcd
+            };
+            sequence = sequence
+                .iter()
+                .map(|p| reduce(p.pseudo_div_rem(&gcd).0))
+                .collect();
+        }
+        Sturm { sequence }
+    }
+
+    /// Isolate the distinct real roots in ascending order with the Vincent–Collins–Akritas bisection method,
+    /// which uses [Descartes' rule of signs](https://en.wikipedia.org/wiki/Descartes%27_rule_of_signs)
+    /// to count roots in each interval.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    /// use num::rational::Ratio;
+    ///
+    /// // (x^2 - 2)(2x - 1) has roots at -√2, 1/2 and √2
+    /// let poly = Poly::new([2, -4, -1, 2]);
+    /// let intervals = poly.isolate_real_roots();
+    /// assert_eq!(intervals.len(), 3);
+    ///
+    /// let sturm = poly.sturm();
+    /// for interval in &intervals {
+    ///     if interval.is_exact() {
+    ///         assert_eq!(interval.lower, Ratio::new(1, 2));
+    ///     } else {
+    ///         assert_eq!(sturm.count(&interval.lower, &interval.upper), 1);
+    ///     }
+    /// }
+    /// for pair in intervals.windows(2) {
+    ///     assert!(pair[0].upper <= pair[1].lower);
+    /// }
+    /// ```
+    pub fn isolate_real_roots(&self) -> Vec<Interval<T>> {
+        if self.degree().is_none_or(|degree| degree == 0) {
+            return Vec::new();
+        }
+
+        // Remove repeated factors, so every root is simple
+        let p = Self::from(self.clone().primitive_part());
+        let g = p.primitive_gcd(&Self::from(p.clone().derivative()));
+        let p = if g.degree() == Some(0) {
+            p
+        } else {
+            Self::from(p.pseudo_div_rem(&g).0.primitive_part())
+        };
+
+        let mut intervals = Vec::new();
+        let zeros = p.iter().position(|c| !c.is_zero()).unwrap();
+        if zeros > 0 {
+            let zero = Ratio::from_integer(T::zero());
+            intervals.push(Interval {
+                lower: zero.clone(),
+                upper: zero,
+            });
+        }
+        let positive = p.coefficients()[zeros..].to_vec();
+        let negative: Vec<T> = positive
+            .iter()
+            .enumerate()
+            .map(|(i, c)| if i % 2 == 1 { -c.clone() } else { c.clone() })
+            .collect();
+
+        intervals.extend(isolate_positive(positive));
+        intervals.extend(
+            isolate_positive(negative)
+                .into_iter()
+                .map(|interval| Interval {
+                    lower: -interval.upper,
+                    upper: -interval.lower,
+                }),
+        );
+        intervals.sort_by(|a, b| a.lower.cmp(&b.lower));
+        intervals
+    }
+}
+
+/// Isolate the positive roots of the square-free polynomial with coefficients `p` and a nonzero constant term.
+fn isolate_positive<T>(p: Vec<T>) -> Vec<Interval<T>>
+where
+    T: Integer + Signed + Clone,
+    for<'a> T: Div<&'a T, Output = T>,
+{
+    let n = p.len() - 1;
+    let two = T::one() + T::one();
+
+    // Cauchy's bound on the positive roots, so they are all in (0, 1) after scaling by it
+    let lead = p[n].abs();
+    let largest = p[..n].iter().map(Signed::abs).max().unwrap_or_else(T::zero);
+    let bound = largest.div_ceil(&lead) + T::one();
+    let scaled: Vec<T> = p
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| c * pow(bound.clone(), i))
+        .collect();
+
+    // Each entry is a polynomial with its roots in (0, 1) mapped from (c / 2^k, (c + 1) / 2^k) times the bound
+    let mut stack = alloc::vec![(scaled, T::zero(), 0)];
+    let mut intervals = Vec::new();
+    let endpoint = |c: T, k: usize| Ratio::new(c * bound.clone(), pow(two.clone(), k));
+    while let Some((f, c, k)) = stack.pop() {
+        // Roots in (0, 1) map to positive roots of (x + 1)^n f(1 / (x + 1))
+        let mut reversed: Vec<T> = f.iter().rev().cloned().collect();
+        taylor_shift(&mut reversed);
+
+        match sign_variations(reversed.iter().map(Signed::signum)) {
+            0 => {}
+            1 => intervals.push(Interval {
+                lower: endpoint(c.clone(), k),
+                upper: endpoint(c + T::one(), k),
+            }),
+            _ => {
+                // Halves with 2^n f(x / 2) for (0, 1/2) and 2^n f((x + 1) / 2) for (1/2, 1)
+                let degree = f.len() - 1;
+                let left: Vec<T> = f
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, a)| a * pow(two.clone(), degree - i))
+                    .collect();
+                let mut right = left.clone();
+                taylor_shift(&mut right);
+
+                let middle = c.clone() * two.clone() + T::one();
+                if right[0].is_zero() {
+                    let root = endpoint(middle.clone(), k + 1);
+                    intervals.push(Interval {
+                        lower: root.clone(),
+                        upper: root,
+                    });
+                    right.remove(0);
+                }
+
+                stack.push((primitive(left), c * two.clone(), k + 1));
+                stack.push((primitive(right), middle, k + 1));
+            }
+        }
+    }
+    intervals
+}
+
+/// Divide by the absolute value of the content, keeping the signs of the coefficients.
+/// The content of a constant is the constant itself.
+fn reduce<T>(p: Poly<T>) -> Poly<T>
+where
+    T: Integer + Signed + Clone,
+    for<'a> T: Div<&'a T, Output = T>,
+{
+    let content = p.clone().content().abs();
+    Poly::from(p.div(content))
+}
+
+fn primitive<T>(p: Vec<T>) -> Vec<T>
+where
+    T: Integer + Clone,
+    for<'a> T: Div<&'a T, Output = T>,
+{
+    p.primitive_part().collect()
+}
+
+/// Replace the coefficients of `p(x)` with those of `p(x + 1)`.
+fn taylor_shift<T: Integer + Clone>(p: &mut [T]) {
+    let n = p.len().saturating_sub(1);
+    for i in 0..n {
+        for j in (i..n).rev() {
+            p[j] = p[j].clone() + p[j + 1].clone();
+        }
+    }
+}
+
+fn sign_variations<T, I>(signs: I) -> usize
+where
+    T: Signed,
+    I: IntoIterator<Item = T>,
+{
+    let mut previous: Option<bool> = None;
+    let mut variations = 0;
+    for sign in signs {
+        if sign.is_zero() {
+            continue;
+        }
+        let positive = sign.is_positive();
+        if previous.is_some_and(|previous| previous != positive) {
+            variations += 1;
+        }
+        previous = Some(positive);
+    }
+    variations
+}
+
+/// Sign of `p` at `x = n / d`, as the sign of `d^m p(n / d)` for degree `m` with `d > 0`.
+fn sign_at<T>(p: &Poly<T>, x: &Ratio<T>) -> T
+where
+    T: Integer + Signed + Clone,
+{
+    let (n, d) = (x.numer(), x.denom());
+    let mut power = T::one();
+    let mut value = T::zero();
+    for c in p.iter().rev() {
+        value = value * n.clone() + c.clone() * power.clone();
+        power = power * d.clone();
+    }
+    value.signum()
+}
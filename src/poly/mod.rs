@@ -0,0 +1,352 @@
+//! Polynomials
+
+mod dense;
+pub use dense::Poly;
+
+mod division;
+pub use division::Field;
+
+mod isolation;
+pub use isolation::{Interval, Sturm};
+
+mod roots;
+pub use roots::{Aberth, DurandKerner, JenkinsTraub, Roots};
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    ops::{Div, Mul},
+};
+use num::{Float, FromPrimitive, Integer, Zero};
+
+pub trait Polynomial: IntoIterator + Sized {
+    /// Evaluate at `x` with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+    ///
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// // 1 - 3x + 2x^2
+    /// let poly = [1., -3., 2.];
+    /// assert_eq!(poly.eval(2.), 3.);
+    /// assert_eq!(poly.iter().eval(0.5), 0.);
+    /// ```
+    fn eval<T>(self, x: T) -> T
+    where
+        Self::IntoIter: DoubleEndedIterator,
+        Self::Item: Borrow<T>,
+        T: Zero + Clone + Mul<Output = T>,
+    {
+        self.into_iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.borrow().clone())
+    }
+
+    /// Evaluate at `x` with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme),
+    /// which splits the work into independent products that can run in parallel.
+    ///
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// let poly = [1., -3., 2., 5., -1.];
+    /// assert_eq!(poly.estrin(3.), poly.eval(3.));
+    ///
+    /// for n in 0..10 {
+    ///     let poly: Vec<i64> = (1..=n).collect();
+    ///     assert_eq!(poly.iter().estrin(2), poly.iter().eval(2));
+    /// }
+    /// ```
+    fn estrin<T>(self, x: T) -> T
+    where
+        Self::Item: Borrow<T>,
+        T: Zero + Clone + Mul<Output = T>,
+    {
+        // Each level pairs up the coefficients in place, halving the buffer
+        let mut coefficients: Vec<T> = self.into_iter().map(|c| c.borrow().clone()).collect();
+        let mut x = x;
+        while coefficients.len() > 1 {
+            let len = coefficients.len();
+            for i in 0..len / 2 {
+                coefficients[i] =
+                    coefficients[2 * i].clone() + coefficients[2 * i + 1].clone() * x.clone();
+            }
+            if len % 2 == 1 {
+                coefficients[len / 2] = coefficients[len - 1].clone();
+            }
+            coefficients.truncate(len.div_ceil(2));
+            x = x.clone() * x;
+        }
+        coefficients.pop().unwrap_or_else(T::zero)
+    }
+
+    /// Evaluate at `x` with the compensated Horner scheme by Graillat, Langlois and Louvet.
+    /// Rounding errors of each step are tracked with error-free transformations and added back,
+    /// so the result is as accurate as Horner's method in twice the working precision.
+    /// This matters near multiple roots, where Horner's method loses most of its digits.
+    ///
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// // (x - 0.75)^5
+    /// let poly = [-0.2373046875, 1.58203125, -4.21875, 5.625, -3.75, 1.];
+    /// let x = 0.7501;
+    /// let exact = (x - 0.75f64).powi(5);
+    ///
+    /// let relative_error = |value: f64| ((value - exact) / exact).abs();
+    /// assert!(relative_error(poly.eval(x)) > 1e-3);
+    /// assert!(relative_error(poly.compensated_eval(x)) < 1e-12);
+    /// ```
+    fn compensated_eval<T>(self, x: T) -> T
+    where
+        Self::IntoIter: DoubleEndedIterator,
+        Self::Item: Borrow<T>,
+        T: Float,
+    {
+        let mut iter = self.into_iter().rev();
+        let mut sum = match iter.next() {
+            Some(c) => *c.borrow(),
+            None => return T::zero(),
+        };
+        let mut correction = T::zero();
+        for c in iter {
+            // Error-free product
+            let product = sum * x;
+            let product_error = sum.mul_add(x, -product);
+
+            // Error-free sum
+            let c = *c.borrow();
+            sum = product + c;
+            let rounded = sum - product;
+            let sum_error = (product - (sum - rounded)) + (c - rounded);
+
+            correction = correction.mul_add(x, product_error + sum_error);
+        }
+        sum + correction
+    }
+
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// let poly = [0., 4.];
+    /// assert!(poly.integral().eq([0., 0., 2.]));
+    /// ```
+    fn integral(self) -> Integral<Self::IntoIter> {
+        Integral {
+            iter: self.into_iter(),
+            pos: 0,
+        }
+    }
+
+    /// Definite integral from `a` to `b`, evaluating the antiderivative from [`integral`](Self::integral) at the bounds.
+    ///
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    /// use num::rational::Ratio;
+    ///
+    /// // 1 + 3x^2
+    /// let poly = [1., 0., 3.];
+    /// assert_eq!(poly.definite_integral(-1., 2.), 12.);
+    ///
+    /// // Exact with rationals, where x integrates to x^2 / 2
+    /// let poly = [0, 1].map(Ratio::from_integer);
+    /// assert_eq!(poly.definite_integral(Ratio::from_integer(0), Ratio::from_integer(2)), Ratio::from_integer(2));
+    /// ```
+    ///
+    /// Integer coefficients are rejected, since integrating them would truncate.
+    ///
+    /// ```compile_fail
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// [0, 1].definite_integral(0, 2);
+    /// ```
+    fn definite_integral(self, a: Self::Item, b: Self::Item) -> Self::Item
+    where
+        Self::Item: Field + FromPrimitive,
+    {
+        let antiderivative: Vec<Self::Item> = self.integral().collect();
+        antiderivative.iter().eval(b) - antiderivative.iter().eval(a)
+    }
+
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// let poly = [1., 2., 3.];
+    /// assert!(poly.derivative().eq([2., 6.]));
+    ///
+    /// // Newton's method for the square root of two
+    /// let poly = [-2., 0., 1.];
+    /// let mut x = 1.;
+    /// for _ in 0..5 {
+    ///     x -= poly.eval(x) / poly.derivative().eval(x);
+    /// }
+    /// assert_eq!(x, 2f64.sqrt());
+    /// ```
+    fn derivative(self) -> Derivative<Self::IntoIter> {
+        self.nth_derivative(1)
+    }
+
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// let poly = [1, 1, 1, 1, 1];
+    /// assert!(poly.nth_derivative(2).eq([2, 6, 12]));
+    /// assert!(poly.nth_derivative(2).rev().eq([12, 6, 2]));
+    /// assert!(poly.nth_derivative(5).eq([]));
+    /// ```
+    fn nth_derivative(self, order: usize) -> Derivative<Self::IntoIter> {
+        Derivative {
+            iter: self.into_iter(),
+            order,
+            pos: 0,
+        }
+    }
+
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// let poly = [-20, 30, 0, -12];
+    /// assert_eq!(poly.content(), 2);
+    /// ```
+    fn content(self) -> Self::Item
+    where
+        Self::Item: Integer,
+    {
+        crate::factor::gcd(self)
+    }
+
+    /// ```
+    /// use quickmaths::poly::Polynomial;
+    ///
+    /// let poly = [-20, 30, 0, -12];
+    /// assert!(poly.primitive_part().eq([-10, 15, 0, -6]));
+    /// ```
+    fn primitive_part(self) -> Divide<Self::IntoIter, Self::Item>
+    where
+        Self::IntoIter: Clone,
+        Self::Item: Integer,
+    {
+        let iter = self.into_iter();
+        iter.clone().div(iter.content())
+    }
+
+    fn div<U>(self, divisor: U) -> Divide<Self::IntoIter, U> {
+        Divide {
+            iter: self.into_iter(),
+            divisor,
+        }
+    }
+}
+
+impl<T> Polynomial for T where T: IntoIterator {}
+
+pub struct Divide<T, U> {
+    iter: T,
+    divisor: U,
+}
+
+impl<T, U> Iterator for Divide<T, U>
+where
+    T: Iterator,
+    T::Item: for<'u> Div<&'u U, Output = T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|lhs| lhs / &self.divisor)
+    }
+}
+
+pub struct Derivative<T> {
+    iter: T,
+    order: usize,
+    pos: usize,
+}
+
+impl<T> Derivative<T>
+where
+    T: Iterator,
+    T::Item: FromPrimitive + Mul<Output = T::Item>,
+{
+    /// Skip the terms below the order, which vanish.
+    fn skip(&mut self) -> Option<()> {
+        while self.pos < self.order {
+            self.iter.next()?;
+            self.pos += 1;
+        }
+        Some(())
+    }
+
+    /// Multiply the coefficient of degree `pos` by the falling factorial of its degree.
+    fn scale(&self, coeff: T::Item, pos: usize) -> T::Item {
+        (0..self.order).fold(coeff, |c, i| c * T::Item::from_usize(pos - i).unwrap())
+    }
+}
+
+impl<T> Iterator for Derivative<T>
+where
+    T: Iterator,
+    T::Item: FromPrimitive + Mul<Output = T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.skip()?;
+        let coeff = self.iter.next().map(|c| self.scale(c, self.pos));
+        self.pos += 1;
+
+        coeff
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let skipped = self.order.saturating_sub(self.pos);
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_sub(skipped),
+            upper.map(|upper| upper.saturating_sub(skipped)),
+        )
+    }
+}
+
+impl<T> DoubleEndedIterator for Derivative<T>
+where
+    T: DoubleEndedIterator + ExactSizeIterator,
+    T::Item: FromPrimitive + Mul<Output = T::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.skip()?;
+        let pos = self.pos + self.iter.len().checked_sub(1)?;
+        self.iter.next_back().map(|c| self.scale(c, pos))
+    }
+}
+
+impl<T> ExactSizeIterator for Derivative<T>
+where
+    T: ExactSizeIterator,
+    T::Item: FromPrimitive + Mul<Output = T::Item>,
+{
+}
+
+pub struct Integral<T: Iterator> {
+    iter: T,
+    pos: usize,
+}
+
+impl<T> Iterator for Integral<T>
+where
+    T: Iterator,
+    T::Item: Zero + FromPrimitive + Div<Output = T::Item>,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let coeff = if self.pos == 0 {
+            Some(T::Item::zero())
+        } else {
+            self.iter
+                .next()
+                .map(|c| c / T::Item::from_usize(self.pos).unwrap())
+        };
+        self.pos += 1;
+
+        coeff
+    }
+}
@@ -0,0 +1,629 @@
+use super::Poly;
+use alloc::vec::Vec;
+use num::{Complex, Float, FromPrimitive, One, Zero};
+
+/// Roots of a polynomial found by iteration.
+#[derive(Clone, Debug)]
+pub struct Roots<T> {
+    /// Every root, repeated by multiplicity.
+    pub roots: Vec<Complex<T>>,
+    pub iterations: usize,
+    /// Whether every root met the tolerance.
+    pub converged: bool,
+}
+
+/// Configuration for [`Poly::aberth`].
+///
+/// A root is accepted once `|p(z)|` is within `2n * tolerance` times `Σ |aᵢ| |z|ⁱ`,
+/// which is the rounding error bound of Horner's method for degree `n` when the tolerance is the machine epsilon.
+pub struct Aberth<T> {
+    pub tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T: Float> Default for Aberth<T> {
+    fn default() -> Self {
+        Self {
+            tolerance: T::epsilon(),
+            max_iterations: 500,
+        }
+    }
+}
+
+/// Configuration for [`Poly::durand_kerner`], with the same acceptance test as [`Aberth`].
+pub struct DurandKerner<T> {
+    pub tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T: Float> Default for DurandKerner<T> {
+    fn default() -> Self {
+        Self {
+            tolerance: T::epsilon(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+/// Configuration for [`Poly::jenkins_traub`], with the same acceptance test as [`Aberth`].
+///
+/// Each root is searched for from up to `max_shifts` fixed shifts, rotated around a circle
+/// whose radius is a lower bound on the moduli of the roots.
+pub struct JenkinsTraub<T> {
+    pub tolerance: T,
+    pub max_shifts: usize,
+}
+
+impl<T: Float> Default for JenkinsTraub<T> {
+    fn default() -> Self {
+        Self {
+            tolerance: T::epsilon(),
+            max_shifts: 20,
+        }
+    }
+}
+
+/// Root finding for real or complex coefficients.
+///
+/// Roots at zero are factored out exactly before solving, so they are returned first.
+impl<C> Poly<C>
+where
+    C: num::complex::ComplexFloat,
+    C::Real: FromPrimitive,
+{
+    /// Roots with the quadratic, cubic or quartic formulas, or `None` for the zero polynomial or degrees above four.
+    ///
+    /// These can lose accuracy for nearly repeated roots, so they may need to be [polished](Self::polish).
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    /// use num::Complex;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // x^2 + 1
+    /// let roots = Poly::new([1., 0., 1.]).closed_form_roots().unwrap();
+    /// assert_eq!(roots, [Complex::new(0., -1.), Complex::new(0., 1.)]);
+    ///
+    /// // (x - 1)(x - 2)(x - 3)(x - 4)
+    /// let mut roots = Poly::new([24., -50., 35., -10., 1.]).closed_form_roots().unwrap();
+    /// roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+    /// for (root, expected) in roots.iter().zip(1..) {
+    ///     assert_relative_eq!(root.re, expected as f64, epsilon = 1e-12);
+    ///     assert_relative_eq!(root.im, 0., epsilon = 1e-12);
+    /// }
+    /// ```
+    pub fn closed_form_roots(&self) -> Option<Vec<Complex<C::Real>>> {
+        let (mut roots, p) = self.factor_zeros()?;
+        match p[..] {
+            [_] => {}
+            [a0, a1] => roots.push(-a0 / a1),
+            [a0, a1, a2] => roots.extend(quadratic(a2, a1, a0)),
+            [a0, a1, a2, a3] => roots.extend(cubic(a3, a2, a1, a0)),
+            [a0, a1, a2, a3, a4] => roots.extend(quartic(a4, a3, a2, a1, a0)),
+            _ => return None,
+        }
+        Some(roots)
+    }
+
+    /// Roots with the [Aberth–Ehrlich method](https://en.wikipedia.org/wiki/Aberth_method),
+    /// which refines approximations of all roots at once and converges cubically to simple roots.
+    ///
+    /// ```
+    /// use quickmaths::poly::{Aberth, Poly};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // Wilkinson's polynomial (x - 1)(x - 2)...(x - 10)
+    /// let poly = (1..=10).fold(Poly::new([1.]), |poly, i| poly * Poly::new([-i as f64, 1.]));
+    /// let result = poly.aberth(Aberth::default());
+    /// assert!(result.converged);
+    ///
+    /// let mut roots = result.roots;
+    /// roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+    /// for (root, expected) in roots.iter().zip(1..) {
+    ///     assert_relative_eq!(root.re, expected as f64, epsilon = 1e-6);
+    ///     assert_relative_eq!(root.im, 0., epsilon = 1e-6);
+    /// }
+    /// ```
+    pub fn aberth(&self, aberth: Aberth<C::Real>) -> Roots<C::Real> {
+        self.simultaneous(aberth.tolerance, aberth.max_iterations, |p, z, k| {
+            let (value, derivative, _) = evaluate(p, z[k]);
+            let sum = z
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != k)
+                .fold(Complex::zero(), |sum, (_, &zj)| sum + (z[k] - zj).inv());
+            (derivative / value - sum).inv()
+        })
+    }
+
+    /// Roots with the [Durand–Kerner method](https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method),
+    /// which refines approximations of all roots at once and converges quadratically to simple roots.
+    ///
+    /// ```
+    /// use quickmaths::poly::{DurandKerner, Poly};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // x^5 - 1
+    /// let result = Poly::new([-1., 0., 0., 0., 0., 1.]).durand_kerner(DurandKerner::default());
+    /// assert!(result.converged);
+    /// for root in result.roots {
+    ///     assert_relative_eq!(root.norm(), 1., epsilon = 1e-12);
+    ///     assert_relative_eq!(root.powu(5).re, 1., epsilon = 1e-12);
+    /// }
+    /// ```
+    pub fn durand_kerner(&self, durand_kerner: DurandKerner<C::Real>) -> Roots<C::Real> {
+        self.simultaneous(
+            durand_kerner.tolerance,
+            durand_kerner.max_iterations,
+            |p, z, k| {
+                let (value, _, _) = evaluate(p, z[k]);
+                let product = z
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != k)
+                    .fold(p[p.len() - 1], |product, (_, &zj)| product * (z[k] - zj));
+                value / product
+            },
+        )
+    }
+
+    /// Roots with the complex [Jenkins–Traub algorithm](https://en.wikipedia.org/wiki/Jenkins%E2%80%93Traub_algorithm),
+    /// which finds one root at a time, smallest first, and deflates the polynomial by it.
+    /// The shifts are restarted from a new angle whenever an iteration fails to converge.
+    ///
+    /// ```
+    /// use quickmaths::poly::{JenkinsTraub, Poly};
+    /// use num::Complex;
+    /// use approx::assert_relative_eq;
+    ///
+    /// let expected = [Complex::new(0., 1.), Complex::new(-2., 0.), Complex::new(1., 1.), Complex::new(3., -2.)];
+    /// let poly = expected
+    ///     .iter()
+    ///     .fold(Poly::new([Complex::new(1., 0.)]), |poly, &root| poly * Poly::new([-root, Complex::new(1., 0.)]));
+    ///
+    /// let result = poly.jenkins_traub(JenkinsTraub::default());
+    /// assert!(result.converged);
+    /// for root in expected {
+    ///     let closest = result.roots.iter().map(|z| (z - root).norm()).fold(f64::MAX, f64::min);
+    ///     assert_relative_eq!(closest, 0., epsilon = 1e-12);
+    /// }
+    /// ```
+    pub fn jenkins_traub(&self, jenkins_traub: JenkinsTraub<C::Real>) -> Roots<C::Real> {
+        let (mut roots, p) = match self.factor_zeros() {
+            Some(factored) => factored,
+            None => return empty(),
+        };
+        let lead = p[p.len() - 1];
+        let mut p: Vec<_> = p.into_iter().map(|c| c / lead).collect();
+
+        let mut iterations = 0;
+        let mut converged = true;
+        while p.len() > 2 {
+            let (root, found) = jenkins_traub_root(&p, &jenkins_traub, &mut iterations);
+            converged &= found;
+            roots.push(root);
+            p = deflate(&p, root);
+        }
+        if let [a0, _] = p[..] {
+            roots.push(-a0);
+        }
+
+        Roots {
+            roots,
+            iterations,
+            converged,
+        }
+    }
+
+    /// Refine approximate `roots` in place with up to `max_iterations` steps of
+    /// [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method) each,
+    /// stopping early once a step no longer reduces `|p(z)|`.
+    ///
+    /// ```
+    /// use quickmaths::poly::Poly;
+    ///
+    /// let poly = Poly::new([24f64, -50., 35., -10., 1.]);
+    /// let mut roots = poly.closed_form_roots().unwrap();
+    /// poly.polish(&mut roots, 5);
+    ///
+    /// for root in roots {
+    ///     assert_eq!(root.im, 0.);
+    ///     assert_eq!(root.re, root.re.round());
+    /// }
+    /// ```
+    pub fn polish(&self, roots: &mut [Complex<C::Real>], max_iterations: usize) {
+        let p = self.to_complex();
+        if p.len() < 2 {
+            return;
+        }
+
+        for z in roots {
+            let (mut value, mut derivative, _) = evaluate(&p, *z);
+            for _ in 0..max_iterations {
+                if value.is_zero() || derivative.is_zero() {
+                    break;
+                }
+
+                let next = *z - value / derivative;
+                let (next_value, next_derivative, _) = evaluate(&p, next);
+                if next_value.norm() >= value.norm() {
+                    break;
+                }
+                *z = next;
+                value = next_value;
+                derivative = next_derivative;
+            }
+        }
+    }
+
+    fn to_complex(&self) -> Vec<Complex<C::Real>> {
+        self.iter()
+            .map(|&c| Complex::new(C::re(c), C::im(c)))
+            .collect()
+    }
+
+    /// Zero roots and the remaining coefficients with a nonzero constant term,
+    /// or `None` for the zero polynomial.
+    #[allow(clippy::type_complexity)]
+    fn factor_zeros(&self) -> Option<(Vec<Complex<C::Real>>, Vec<Complex<C::Real>>)> {
+        let mut p = self.to_complex();
+        let zeros = p.iter().position(|c| !c.is_zero())?;
+        p.drain(..zeros);
+        Some((alloc::vec![Complex::zero(); zeros], p))
+    }
+
+    /// Refine all roots at once from points on a circle, subtracting the correction from `step` for each root
+    /// in turn until they are all accepted.
+    fn simultaneous<S>(
+        &self,
+        tolerance: C::Real,
+        max_iterations: usize,
+        mut step: S,
+    ) -> Roots<C::Real>
+    where
+        S: FnMut(&[Complex<C::Real>], &[Complex<C::Real>], usize) -> Complex<C::Real>,
+    {
+        let (mut roots, p) = match self.factor_zeros() {
+            Some(factored) => factored,
+            None => return empty(),
+        };
+        let n = p.len() - 1;
+        if n == 0 {
+            return Roots {
+                roots,
+                iterations: 0,
+                converged: true,
+            };
+        }
+        let real = |x: f64| C::Real::from_f64(x).unwrap();
+        let degree = C::Real::from_usize(n).unwrap();
+        let tolerance = real(2.) * degree * tolerance;
+
+        // Start on a circle with the geometric mean of the moduli of the roots, rotated off the axes
+        let radius = (p[0].norm() / p[n].norm()).powf(degree.recip());
+        let mut z: Vec<_> = (0..n)
+            .map(|k| {
+                let angle = real(core::f64::consts::TAU) * C::Real::from_usize(k).unwrap() / degree;
+                Complex::from_polar(radius, angle + real(0.4))
+            })
+            .collect();
+
+        let mut done = alloc::vec![false; n];
+        let mut iterations = 0;
+        while iterations < max_iterations && done.contains(&false) {
+            iterations += 1;
+            for k in 0..n {
+                if done[k] {
+                    continue;
+                }
+                let (value, _, bound) = evaluate(&p, z[k]);
+                if value.norm() <= tolerance * bound {
+                    done[k] = true;
+                    continue;
+                }
+
+                let correction = step(&p, &z, k);
+                z[k] = z[k] - correction;
+                if correction.norm() <= C::Real::epsilon() * z[k].norm() {
+                    done[k] = true;
+                }
+            }
+        }
+
+        let converged = !done.contains(&false);
+        roots.extend(z);
+        Roots {
+            roots,
+            iterations,
+            converged,
+        }
+    }
+}
+
+fn empty<T>() -> Roots<T> {
+    Roots {
+        roots: Vec::new(),
+        iterations: 0,
+        converged: true,
+    }
+}
+
+/// Value and derivative of the polynomial with coefficients `p` at `z`,
+/// with `Σ |pᵢ| |z|ⁱ` to bound the rounding error of the value.
+fn evaluate<T: Float>(p: &[Complex<T>], z: Complex<T>) -> (Complex<T>, Complex<T>, T) {
+    let modulus = z.norm();
+    let mut value = Complex::zero();
+    let mut derivative = Complex::zero();
+    let mut bound = T::zero();
+    for &c in p.iter().rev() {
+        derivative = derivative * z + value;
+        value = value * z + c;
+        bound = bound * modulus + c.norm();
+    }
+    (value, derivative, bound)
+}
+
+/// Quotient of `p` by `z - root`.
+fn deflate<T: Float>(p: &[Complex<T>], root: Complex<T>) -> Vec<Complex<T>> {
+    let n = p.len() - 1;
+    let mut quotient = alloc::vec![Complex::zero(); n];
+    quotient[n - 1] = p[n];
+    for i in (1..n).rev() {
+        quotient[i - 1] = p[i] + root * quotient[i];
+    }
+    quotient
+}
+
+fn quadratic<T: Float>(a: Complex<T>, b: Complex<T>, c: Complex<T>) -> [Complex<T>; 2] {
+    let two = T::one() + T::one();
+    let discriminant = (b * b - a * c * two * two).sqrt();
+
+    // Avoid cancellation by adding terms in the same direction, then use Vieta's formula for the other root
+    let q = if (b.conj() * discriminant).re >= T::zero() {
+        -(b + discriminant) / two
+    } else {
+        -(b - discriminant) / two
+    };
+    if q.is_zero() {
+        [Complex::zero(); 2]
+    } else {
+        let (x1, x2) = (q / a, c / q);
+        if (x1.re, x1.im) <= (x2.re, x2.im) {
+            [x1, x2]
+        } else {
+            [x2, x1]
+        }
+    }
+}
+
+/// Roots of `a x^3 + b x^2 + c x + d` with Cardano's formula.
+fn cubic<T>(a: Complex<T>, b: Complex<T>, c: Complex<T>, d: Complex<T>) -> [Complex<T>; 3]
+where
+    T: Float + FromPrimitive,
+{
+    let real = |x: f64| T::from_f64(x).unwrap();
+    let (b, c, d) = (b / a, c / a, d / a);
+
+    // Depressed cubic t^3 + p t + q with x = t - b / 3
+    let p = c - b * b / real(3.);
+    let q = b * b * b * real(2. / 27.) - b * c / real(3.) + d;
+
+    let root = (q * q / real(4.) + p * p * p / real(27.)).sqrt();
+    let (plus, minus) = (-q / real(2.) + root, -q / real(2.) - root);
+    let cube = if plus.norm() >= minus.norm() {
+        plus
+    } else {
+        minus
+    };
+    let u = if cube.is_zero() {
+        Complex::zero()
+    } else {
+        cube.powf(real(1. / 3.))
+    };
+
+    let rotation = Complex::new(real(-0.5), real(3f64.sqrt() / 2.));
+    let mut roots = [Complex::zero(); 3];
+    let mut uk = u;
+    for root in &mut roots {
+        let vk = if uk.is_zero() {
+            Complex::zero()
+        } else {
+            -p / (uk * real(3.))
+        };
+        *root = uk + vk - b / real(3.);
+        uk = uk * rotation;
+    }
+    roots
+}
+
+/// Roots of `a x^4 + b x^3 + c x^2 + d x + e` with Ferrari's method.
+fn quartic<T>(
+    a: Complex<T>,
+    b: Complex<T>,
+    c: Complex<T>,
+    d: Complex<T>,
+    e: Complex<T>,
+) -> [Complex<T>; 4]
+where
+    T: Float + FromPrimitive,
+{
+    let real = |x: f64| T::from_f64(x).unwrap();
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+    let shift = -b / real(4.);
+
+    // Depressed quartic y^4 + p y^2 + q y + r with x = y - b / 4
+    let b2 = b * b;
+    let p = c - b2 * real(3. / 8.);
+    let q = d - b * c / real(2.) + b2 * b / real(8.);
+    let r = e - b * d / real(4.) + b2 * c / real(16.) - b2 * b2 * real(3. / 256.);
+
+    let one = Complex::one();
+    let [y1, y2, y3, y4] = if q.is_zero() {
+        // Biquadratic
+        let [z1, z2] = quadratic(one, p, r);
+        let (s1, s2) = (z1.sqrt(), z2.sqrt());
+        [s1, -s1, s2, -s2]
+    } else {
+        // Any nonzero root of the resolvent cubic completes the square, so take the largest
+        let m = cubic(one, p, p * p / real(4.) - r, -q * q / real(8.))
+            .into_iter()
+            .fold(Complex::zero(), |m: Complex<T>, root| {
+                if root.norm() > m.norm() {
+                    root
+                } else {
+                    m
+                }
+            });
+        let s = (m * real(2.)).sqrt();
+        let half = p / real(2.) + m;
+        let [y1, y2] = quadratic(one, s, half - q / (s * real(2.)));
+        let [y3, y4] = quadratic(one, -s, half + q / (s * real(2.)));
+        [y1, y2, y3, y4]
+    };
+    [y1 + shift, y2 + shift, y3 + shift, y4 + shift]
+}
+
+/// Unique positive root of `xⁿ + |pₙ₋₁| xⁿ⁻¹ + ... + |p₁| x - |p₀|` for monic `p`,
+/// which bounds the moduli of the roots from below.
+fn cauchy_lower_bound<T: Float + FromPrimitive>(p: &[Complex<T>]) -> T {
+    let n = p.len() - 1;
+    let moduli: Vec<T> = p.iter().map(|c| c.norm()).collect();
+    let f = |x: T| {
+        moduli[1..]
+            .iter()
+            .rev()
+            .fold(T::zero(), |sum, &m| sum * x + m)
+            * x
+            - moduli[0]
+    };
+    let derivative = |x: T| {
+        moduli[1..]
+            .iter()
+            .enumerate()
+            .rev()
+            .fold(T::zero(), |sum, (i, &m)| {
+                sum * x + m * T::from_usize(i + 1).unwrap()
+            })
+    };
+
+    // Newton's method decreases monotonically from the geometric mean, which is above the root
+    let mut x = moduli[0].powf(T::from_usize(n).unwrap().recip());
+    for _ in 0..100 {
+        let step = f(x) / derivative(x);
+        x = x - step;
+        if step.abs() <= T::from_f64(0.005).unwrap() * x {
+            break;
+        }
+    }
+    x
+}
+
+/// Value of the polynomial `h` at `z`.
+fn value<T: Float>(h: &[Complex<T>], z: Complex<T>) -> Complex<T> {
+    h.iter()
+        .rev()
+        .fold(Complex::zero(), |value, &c| value * z + c)
+}
+
+/// Update the monic H polynomial with shift `s` for monic `p`, where `ps` is `p(s)`.
+fn shift_h<T: Float>(p: &[Complex<T>], h: &mut Vec<Complex<T>>, s: Complex<T>, ps: Complex<T>) {
+    let n = p.len() - 1;
+    let hs = value(h, s);
+
+    let ten = T::from(10).unwrap();
+    let numerator: Vec<Complex<T>> = if hs.norm() <= ten * T::epsilon() * h[n - 1].norm() {
+        // H is nearly zero at the shift, so drop the p term: z (H(z) - H(s)) / (z - s)
+        let mut numerator = alloc::vec![Complex::zero()];
+        numerator.extend_from_slice(h);
+        numerator[1] = numerator[1] - hs;
+        numerator
+    } else {
+        let c = hs / ps;
+        let mut numerator: Vec<_> = h.iter().zip(p).map(|(&h, &p)| h - c * p).collect();
+        numerator.push(-c * p[n]);
+        numerator
+    };
+
+    let mut quotient = deflate(&numerator, s);
+    if let Some(&lead) = quotient.last() {
+        if !lead.is_zero() {
+            for q in &mut quotient {
+                *q = *q / lead;
+            }
+        }
+    }
+    *h = quotient;
+}
+
+/// One root of the monic polynomial `p` of degree at least two, smallest first, and whether it converged.
+fn jenkins_traub_root<T>(
+    p: &[Complex<T>],
+    jenkins_traub: &JenkinsTraub<T>,
+    iterations: &mut usize,
+) -> (Complex<T>, bool)
+where
+    T: Float + FromPrimitive,
+{
+    let real = |x: f64| T::from_f64(x).unwrap();
+    let n = p.len() - 1;
+    let tolerance = real(2.) * T::from_usize(n).unwrap() * jenkins_traub.tolerance;
+
+    // Stage one: no shift, starting from the scaled derivative
+    let mut h: Vec<_> = (1..=n)
+        .map(|i| p[i] * T::from_usize(i).unwrap() / T::from_usize(n).unwrap())
+        .collect();
+    for _ in 0..5 {
+        shift_h(p, &mut h, Complex::zero(), p[0]);
+        *iterations += 1;
+    }
+
+    let beta = cauchy_lower_bound(p);
+    let rotation = real(94f64.to_radians());
+    let mut angle = real(49f64.to_radians());
+    let mut last = Complex::from_polar(beta, angle);
+    for shifts in 1..=jenkins_traub.max_shifts {
+        angle = angle + rotation;
+        let s = Complex::from_polar(beta, angle);
+        let saved = h.clone();
+
+        // Stage two: fixed shift until the estimates settle
+        let ps = value(p, s);
+        if ps.is_zero() {
+            return (s, true);
+        }
+        let mut previous: Option<Complex<T>> = None;
+        let mut passed = false;
+        let mut estimate = None;
+        for _ in 0..10 * shifts {
+            shift_h(p, &mut h, s, ps);
+            *iterations += 1;
+            let t = s - ps / value(&h, s);
+            if let Some(previous) = previous {
+                let pass = (t - previous).norm() <= real(0.5) * previous.norm();
+                if pass && passed {
+                    estimate = Some(t);
+                    break;
+                }
+                passed = pass;
+            }
+            previous = Some(t);
+        }
+
+        // Stage three: variable shifts
+        if let Some(mut s) = estimate {
+            for _ in 0..10 {
+                let (ps, _, bound) = evaluate(p, s);
+                if ps.norm() <= tolerance * bound {
+                    return (s, true);
+                }
+                shift_h(p, &mut h, s, ps);
+                *iterations += 1;
+                s = s - ps / value(&h, s);
+            }
+            last = s;
+        }
+        h = saved;
+    }
+
+    (last, false)
+}
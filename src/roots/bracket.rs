@@ -0,0 +1,469 @@
+use super::{is_finite, same_sign, Root, Status, Termination};
+use num::{traits::real::Real, FromPrimitive};
+
+/// Root of function `f` bracketed by `start` and `end`, where `f` changes sign.
+///
+/// ```
+/// use quickmaths::roots::{Bracket, Termination};
+/// use quickmaths::stats::{Distribution, ErrorFunction, NormalDistribution};
+/// use approx::assert_relative_eq;
+///
+/// // The 30% quantile of the standard normal distribution
+/// let normal = NormalDistribution::standard();
+/// let root = Bracket::new(-1., 0., |x| normal.cdf(&x, ErrorFunction::default()) - 0.3)
+///     .brent(Termination::default());
+///
+/// assert!(root.converged());
+/// assert_relative_eq!(root.value, -0.5244005127080407, epsilon = 1e-5);
+/// ```
+pub struct Bracket<F, T> {
+    start: T,
+    end: T,
+    f: F,
+}
+
+impl<F, T> Bracket<F, T> {
+    pub fn new(start: T, end: T, f: F) -> Self {
+        Self { start, end, f }
+    }
+}
+
+impl<F, T> Bracket<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real + FromPrimitive,
+{
+    /// Find the root with the [bisection method](https://en.wikipedia.org/wiki/Bisection_method),
+    /// halving the bracket each iteration.
+    ///
+    /// ```
+    /// use quickmaths::roots::{Bracket, Termination};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let root = Bracket::new(1., 2., |x: f64| x * x - 2.).bisection(Termination::default());
+    /// assert!(root.converged());
+    /// assert_relative_eq!(root.value, 2f64.sqrt(), epsilon = 1e-15);
+    /// assert!(root.iterations > 45);
+    /// ```
+    pub fn bisection(&mut self, termination: Termination<T>) -> Root<T> {
+        let (mut a, mut b) = self.ordered();
+        let (mut fa, fb) = ((self.f)(a), (self.f)(b));
+        if let Some(root) = Self::endpoint(a, fa, b, fb) {
+            return root;
+        }
+
+        let two = T::from_u8(2).unwrap();
+        for iterations in 1..=termination.max_iterations {
+            let half = (b - a) / two;
+            let m = a + half;
+            let fm = (self.f)(m);
+            if fm.is_zero() || half <= termination.tolerance(m) {
+                return Root {
+                    value: m,
+                    error: half,
+                    iterations,
+                    status: Status::Converged,
+                };
+            }
+
+            if same_sign(fa, fm) {
+                a = m;
+                fa = fm;
+            } else {
+                b = m;
+            }
+        }
+
+        Root {
+            value: a + (b - a) / two,
+            error: (b - a) / two,
+            iterations: termination.max_iterations,
+            status: Status::MaxIterations,
+        }
+    }
+
+    /// Find the root with [Brent's method](https://en.wikipedia.org/wiki/Brent%27s_method),
+    /// combining inverse quadratic interpolation and secant steps with bisection as a fallback.
+    ///
+    /// ```
+    /// use quickmaths::roots::{Bracket, Termination};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let root = Bracket::new(0., 1., |x: f64| x.cos() - x).brent(Termination::default());
+    /// assert!(root.converged());
+    /// assert_relative_eq!(root.value, 0.7390851332151607, epsilon = 1e-15);
+    /// assert!(root.iterations < 10);
+    /// ```
+    pub fn brent(&mut self, termination: Termination<T>) -> Root<T> {
+        let (mut a, mut b) = (self.start, self.end);
+        let (mut fa, mut fb) = ((self.f)(a), (self.f)(b));
+        if let Some(root) = Self::endpoint(a, fa, b, fb) {
+            return root;
+        }
+
+        let two = T::from_u8(2).unwrap();
+        let three = T::from_u8(3).unwrap();
+
+        let (mut c, mut fc) = (a, fa);
+        let mut d = b - a;
+        let mut e = d;
+
+        for iterations in 1..=termination.max_iterations {
+            if same_sign(fb, fc) {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+            // Keep `b` as the best estimate
+            if fc.abs() < fb.abs() {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+
+            let tolerance = termination.tolerance(b);
+            let m = (c - b) / two;
+            if m.abs() <= tolerance || fb.is_zero() {
+                return Root {
+                    value: b,
+                    error: m.abs(),
+                    iterations: iterations - 1,
+                    status: Status::Converged,
+                };
+            }
+
+            if e.abs() < tolerance || fa.abs() <= fb.abs() {
+                d = m;
+                e = m;
+            } else {
+                let s = fb / fa;
+                let (mut p, mut q) = if a == c {
+                    // Secant step
+                    (two * m * s, T::one() - s)
+                } else {
+                    // Inverse quadratic interpolation
+                    let q = fa / fc;
+                    let r = fb / fc;
+                    (
+                        s * (two * m * q * (q - r) - (b - a) * (r - T::one())),
+                        (q - T::one()) * (r - T::one()) * (s - T::one()),
+                    )
+                };
+                if p > T::zero() {
+                    q = -q;
+                } else {
+                    p = -p;
+                }
+
+                let previous = e;
+                e = d;
+                if two * p < three * m * q - (tolerance * q).abs() && p < (previous * q / two).abs()
+                {
+                    d = p / q;
+                } else {
+                    d = m;
+                    e = m;
+                }
+            }
+
+            a = b;
+            fa = fb;
+            b = if d.abs() > tolerance {
+                b + d
+            } else if m > T::zero() {
+                b + tolerance
+            } else {
+                b - tolerance
+            };
+            fb = (self.f)(b);
+        }
+
+        Root {
+            value: b,
+            error: ((c - b) / two).abs(),
+            iterations: termination.max_iterations,
+            status: Status::MaxIterations,
+        }
+    }
+
+    /// Find the root with [TOMS 748](https://doi.org/10.1145/210089.210111) by Alefeld, Potra and Shi,
+    /// which uses cubic inverse interpolation and double-length secant steps to shrink the bracket
+    /// itself, not just the best estimate, with asymptotically optimal efficiency.
+    ///
+    /// ```
+    /// use quickmaths::roots::{Bracket, Termination};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let root = Bracket::new(0., 3., |x: f64| x.exp() - 10.).toms748(Termination::default());
+    /// assert!(root.converged());
+    /// assert_relative_eq!(root.value, 10f64.ln(), epsilon = 1e-14);
+    /// assert!(root.iterations < 12);
+    /// ```
+    pub fn toms748(&mut self, termination: Termination<T>) -> Root<T> {
+        let (a, b) = self.ordered();
+        let (fa, fb) = ((self.f)(a), (self.f)(b));
+        if let Some(root) = Self::endpoint(a, fa, b, fb) {
+            return root;
+        }
+
+        let mut state = Toms748 {
+            a,
+            b,
+            fa,
+            fb,
+            d: a,
+            fd: fa,
+            iterations: 0,
+        };
+        let two = T::from_u8(2).unwrap();
+        let done = |state: &Toms748<T>| {
+            state.fa.is_zero()
+                || state.fb.is_zero()
+                || state.b - state.a
+                    <= two * termination.tolerance(state.a.abs().min(state.b.abs()))
+        };
+        let exhausted = |state: &Toms748<T>| state.iterations >= termination.max_iterations;
+
+        let c = secant(a, b, fa, fb);
+        state.bracket(c, &mut self.f);
+        let (mut e, mut fe) = (state.d, state.fd);
+
+        if !done(&state) && !exhausted(&state) {
+            let c = state.quadratic(2);
+            e = state.d;
+            fe = state.fd;
+            state.bracket(c, &mut self.f);
+        }
+
+        while !done(&state) && !exhausted(&state) {
+            let width = state.b - state.a;
+
+            // Two interpolation steps, cubic when the four points have distinct values
+            for newton_steps in [2, 3] {
+                let c = if state.distinct(fe) {
+                    state.cubic(e, fe)
+                } else {
+                    state.quadratic(newton_steps)
+                };
+                e = state.d;
+                fe = state.fd;
+                state.bracket(c, &mut self.f);
+                if done(&state) || exhausted(&state) {
+                    break;
+                }
+            }
+            if done(&state) || exhausted(&state) {
+                break;
+            }
+
+            // Double-length secant step from the best endpoint
+            let (u, fu) = if state.fa.abs() < state.fb.abs() {
+                (state.a, state.fa)
+            } else {
+                (state.b, state.fb)
+            };
+            let mut c = u - two * fu / (state.fb - state.fa) * (state.b - state.a);
+            if (c - u).abs() > (state.b - state.a) / two {
+                c = state.a + (state.b - state.a) / two;
+            }
+            e = state.d;
+            fe = state.fd;
+            state.bracket(c, &mut self.f);
+            if done(&state) || exhausted(&state) {
+                break;
+            }
+
+            // Bisect if the bracket didn't shrink enough
+            if state.b - state.a >= width / two {
+                e = state.d;
+                fe = state.fd;
+                let c = state.a + (state.b - state.a) / two;
+                state.bracket(c, &mut self.f);
+            }
+        }
+
+        let status = if done(&state) {
+            Status::Converged
+        } else {
+            Status::MaxIterations
+        };
+        let (value, error) = if state.fa.is_zero() {
+            (state.a, T::zero())
+        } else if state.fb.is_zero() {
+            (state.b, T::zero())
+        } else {
+            (
+                state.a + (state.b - state.a) / two,
+                (state.b - state.a) / two,
+            )
+        };
+        Root {
+            value,
+            error,
+            iterations: state.iterations,
+            status,
+        }
+    }
+
+    fn ordered(&self) -> (T, T) {
+        if self.start <= self.end {
+            (self.start, self.end)
+        } else {
+            (self.end, self.start)
+        }
+    }
+
+    /// Result for a root at an endpoint or a bracket without a sign change.
+    fn endpoint(a: T, fa: T, b: T, fb: T) -> Option<Root<T>> {
+        let (value, status) = if fa.is_zero() {
+            (a, Status::Converged)
+        } else if fb.is_zero() {
+            (b, Status::Converged)
+        } else if same_sign(fa, fb) {
+            (a, Status::NotBracketed)
+        } else {
+            return None;
+        };
+
+        Some(Root {
+            value,
+            error: if status == Status::Converged {
+                T::zero()
+            } else {
+                (b - a).abs()
+            },
+            iterations: 0,
+            status,
+        })
+    }
+}
+
+/// Secant step between `a` and `b`, falling back to the midpoint if it lands too close to an end.
+fn secant<T>(a: T, b: T, fa: T, fb: T) -> T
+where
+    T: Real + FromPrimitive,
+{
+    let tolerance = T::epsilon() * T::from_u8(5).unwrap();
+    let c = a - fa / (fb - fa) * (b - a);
+    if c <= a + a.abs() * tolerance || c >= b - b.abs() * tolerance || !is_finite(c) {
+        a + (b - a) / T::from_u8(2).unwrap()
+    } else {
+        c
+    }
+}
+
+/// Bracket `[a, b]` of TOMS 748, along with the last point `d` to be discarded from it.
+struct Toms748<T> {
+    a: T,
+    b: T,
+    fa: T,
+    fb: T,
+    d: T,
+    fd: T,
+    iterations: usize,
+}
+
+impl<T> Toms748<T>
+where
+    T: Real + FromPrimitive,
+{
+    /// Evaluate `f` at `c`, kept strictly inside the bracket, and shrink the bracket to the side
+    /// containing the root.
+    fn bracket<F>(&mut self, mut c: T, f: &mut F)
+    where
+        F: FnMut(T) -> T,
+    {
+        let two = T::from_u8(2).unwrap();
+        let tolerance = T::epsilon() * two;
+        if self.b - self.a < two * tolerance * self.a.abs() {
+            c = self.a + (self.b - self.a) / two;
+        } else if c <= self.a + self.a.abs() * tolerance {
+            c = self.a + self.a.abs() * tolerance;
+        } else if c >= self.b - self.b.abs() * tolerance {
+            c = self.b - self.b.abs() * tolerance;
+        }
+        if c <= self.a || c >= self.b {
+            c = self.a + (self.b - self.a) / two;
+        }
+
+        let fc = f(c);
+        self.iterations += 1;
+
+        if fc.is_zero() {
+            self.a = c;
+            self.fa = fc;
+            self.d = T::zero();
+            self.fd = T::zero();
+        } else if same_sign(self.fa, fc) {
+            self.d = self.a;
+            self.fd = self.fa;
+            self.a = c;
+            self.fa = fc;
+        } else {
+            self.d = self.b;
+            self.fd = self.fb;
+            self.b = c;
+            self.fb = fc;
+        }
+    }
+
+    /// Whether `fa`, `fb`, `fd` and `fe` are far enough apart for cubic interpolation.
+    fn distinct(&self, fe: T) -> bool {
+        let min = T::min_positive_value() * T::from_u8(32).unwrap();
+        let values = [self.fa, self.fb, self.fd, fe];
+        (0..4).all(|i| (i + 1..4).all(|j| (values[i] - values[j]).abs() >= min))
+    }
+
+    /// Zero of the quadratic through `a`, `b` and `d`, found with `steps` Newton steps.
+    fn quadratic(&self, steps: usize) -> T {
+        let (a, b, d) = (self.a, self.b, self.d);
+        let (fa, fb, fd) = (self.fa, self.fb, self.fd);
+
+        let b_coefficient = (fb - fa) / (b - a);
+        let a_coefficient = ((fd - fb) / (d - b) - b_coefficient) / (d - a);
+        if a_coefficient.is_zero() || !is_finite(a_coefficient) {
+            return secant(a, b, fa, fb);
+        }
+
+        let mut c = if same_sign(a_coefficient, fa) { a } else { b };
+        for _ in 0..steps {
+            c = c
+                - (fa + (b_coefficient + a_coefficient * (c - b)) * (c - a))
+                    / (b_coefficient + a_coefficient * (c + c - a - b));
+        }
+
+        if c <= a || c >= b || !is_finite(c) {
+            secant(a, b, fa, fb)
+        } else {
+            c
+        }
+    }
+
+    /// Zero of the inverse cubic through `a`, `b`, `d` and `e`.
+    fn cubic(&self, e: T, fe: T) -> T {
+        let (a, b, d) = (self.a, self.b, self.d);
+        let (fa, fb, fd) = (self.fa, self.fb, self.fd);
+
+        let q11 = (d - e) * fd / (fe - fd);
+        let q21 = (b - d) * fb / (fd - fb);
+        let q31 = (a - b) * fa / (fb - fa);
+        let d21 = (b - d) * fd / (fd - fb);
+        let d31 = (a - b) * fb / (fb - fa);
+
+        let q22 = (d21 - q11) * fb / (fe - fb);
+        let q32 = (d31 - q21) * fa / (fd - fa);
+        let d32 = (d31 - q21) * fd / (fd - fa);
+        let q33 = (d32 - q22) * fa / (fe - fa);
+
+        let c = a + q31 + q32 + q33;
+        if c <= a || c >= b || !is_finite(c) {
+            self.quadratic(3)
+        } else {
+            c
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use super::{is_finite, Root, Status, Termination};
+use num::{traits::real::Real, FromPrimitive};
+
+/// Root of function `f` found by iterating from an initial `guess`,
+/// where `f` also returns derivatives of the function.
+pub struct Iteration<F, T> {
+    guess: T,
+    f: F,
+}
+
+impl<F, T> Iteration<F, T> {
+    pub fn new(guess: T, f: F) -> Self {
+        Self { guess, f }
+    }
+}
+
+impl<F, T> Iteration<F, T>
+where
+    T: Real + FromPrimitive,
+{
+    /// Iterate `step` from the guess until it is within tolerance.
+    /// `step` returns the next correction to subtract, or `None` if the derivative vanished.
+    fn iterate<S>(&mut self, termination: Termination<T>, mut step: S) -> Root<T>
+    where
+        S: FnMut(&mut F, T) -> Option<(T, T)>,
+    {
+        let mut x = self.guess;
+        let mut error = T::max_value();
+
+        for iterations in 1..=termination.max_iterations {
+            let (value, delta) = match step(&mut self.f, x) {
+                Some(step) => step,
+                None => {
+                    return Root {
+                        value: x,
+                        error,
+                        iterations,
+                        status: Status::ZeroDerivative,
+                    }
+                }
+            };
+            if value.is_zero() {
+                return Root {
+                    value: x,
+                    error: T::zero(),
+                    iterations,
+                    status: Status::Converged,
+                };
+            }
+
+            x = x - delta;
+            error = delta.abs();
+            if error <= termination.tolerance(x) {
+                return Root {
+                    value: x,
+                    error,
+                    iterations,
+                    status: Status::Converged,
+                };
+            }
+        }
+
+        Root {
+            value: x,
+            error,
+            iterations: termination.max_iterations,
+            status: Status::MaxIterations,
+        }
+    }
+}
+
+impl<F, T> Iteration<F, T>
+where
+    F: FnMut(T) -> (T, T),
+    T: Real + FromPrimitive,
+{
+    /// Find the root with [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method),
+    /// where `f` returns the function and its first derivative.
+    ///
+    /// ```
+    /// use quickmaths::roots::{Iteration, Termination};
+    /// use quickmaths::stats::{Density, Distribution, ErrorFunction, NormalDistribution};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // The 30% quantile of the standard normal distribution
+    /// let normal = NormalDistribution::standard();
+    /// let root = Iteration::new(0., |x| {
+    ///     (normal.cdf(&x, ErrorFunction::default()) - 0.3, normal.pdf(&x))
+    /// })
+    /// .newton_raphson(Termination::default());
+    ///
+    /// assert!(root.converged());
+    /// assert_relative_eq!(root.value, -0.5244005127080407, epsilon = 1e-5);
+    /// ```
+    pub fn newton_raphson(&mut self, termination: Termination<T>) -> Root<T> {
+        self.iterate(termination, |f, x| {
+            let (value, derivative) = f(x);
+            newton_step(value, derivative)
+        })
+    }
+}
+
+impl<F, T> Iteration<F, T>
+where
+    F: FnMut(T) -> (T, T, T),
+    T: Real + FromPrimitive,
+{
+    /// Find the root with [Halley's method](https://en.wikipedia.org/wiki/Halley%27s_method),
+    /// which converges cubically, where `f` returns the function and its first and second derivatives.
+    ///
+    /// ```
+    /// use quickmaths::roots::{Iteration, Termination};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let root = Iteration::new(1., |x: f64| (x.powi(3) - 5., 3. * x * x, 6. * x))
+    ///     .halley(Termination::default());
+    /// assert!(root.converged());
+    /// assert_relative_eq!(root.value, 5f64.cbrt(), epsilon = 1e-15);
+    /// assert!(root.iterations <= 5);
+    /// ```
+    pub fn halley(&mut self, termination: Termination<T>) -> Root<T> {
+        self.iterate(termination, |f, x| {
+            let (value, first, second) = f(x);
+            let denominator = first * first - value * second / T::from_u8(2).unwrap();
+            if denominator.is_zero() {
+                return newton_step(value, first);
+            }
+            Some((value, value * first / denominator))
+        })
+    }
+
+    /// Find the root with Schröder's method, where `f` returns the function and its first and second
+    /// derivatives. The correction `f / f' + f'' f² / (2 f'³)` converges cubically like Halley's
+    /// method near the root, and falls back to Newton steps when the correction is too large.
+    ///
+    /// ```
+    /// use quickmaths::roots::{Iteration, Termination};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let root = Iteration::new(1., |x: f64| (x.ln() * x - 2., x.ln() + 1., 1. / x))
+    ///     .schroder(Termination::default());
+    /// assert!(root.converged());
+    /// assert_relative_eq!(root.value.ln() * root.value, 2., epsilon = 1e-14);
+    /// ```
+    pub fn schroder(&mut self, termination: Termination<T>) -> Root<T> {
+        self.iterate(termination, |f, x| {
+            let (value, first, second) = f(x);
+            let (_, newton) = newton_step(value, first)?;
+            let correction = second * newton * newton / (first + first);
+            // Far from the root the correction can overwhelm the Newton step
+            if is_finite(correction) && correction.abs() < newton.abs() / T::from_u8(2).unwrap() {
+                Some((value, newton + correction))
+            } else {
+                Some((value, newton))
+            }
+        })
+    }
+}
+
+/// Newton correction `f / f'`, or `None` if the derivative vanishes away from a root.
+fn newton_step<T: Real>(value: T, derivative: T) -> Option<(T, T)> {
+    if derivative.is_zero() {
+        if value.is_zero() {
+            Some((value, T::zero()))
+        } else {
+            None
+        }
+    } else {
+        Some((value, value / derivative))
+    }
+}
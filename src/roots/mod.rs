@@ -0,0 +1,81 @@
+//! Root finding
+
+mod bracket;
+pub use bracket::Bracket;
+
+mod iteration;
+pub use iteration::Iteration;
+
+use num::{traits::real::Real, FromPrimitive};
+
+/// Termination criteria for root finding and one-dimensional minimization.
+///
+/// A root or minimum is accepted once it is known to within `absolute_tolerance + relative_tolerance * |x|`,
+/// or after `max_iterations` iterations each evaluating the function once.
+#[derive(Clone, Copy, Debug)]
+pub struct Termination<T> {
+    pub absolute_tolerance: T,
+    pub relative_tolerance: T,
+    pub max_iterations: usize,
+}
+
+impl<T> Default for Termination<T>
+where
+    T: Real + FromPrimitive,
+{
+    fn default() -> Self {
+        Self {
+            absolute_tolerance: T::epsilon(),
+            relative_tolerance: T::from_u8(2).unwrap() * T::epsilon(),
+            max_iterations: 100,
+        }
+    }
+}
+
+impl<T> Termination<T>
+where
+    T: Real,
+{
+    pub(crate) fn tolerance(&self, x: T) -> T {
+        self.absolute_tolerance + self.relative_tolerance * x.abs()
+    }
+}
+
+/// Reason a root finder stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The root is known to within the tolerance or the function is exactly zero.
+    Converged,
+    /// The iteration limit was reached first.
+    MaxIterations,
+    /// The function has the same sign at both ends of the bracket.
+    NotBracketed,
+    /// The derivative vanished so no step could be taken.
+    ZeroDerivative,
+}
+
+/// Approximate root of a function.
+#[derive(Clone, Copy, Debug)]
+pub struct Root<T> {
+    pub value: T,
+    /// Bound on the absolute error, from the final bracket or step size.
+    pub error: T,
+    pub iterations: usize,
+    pub status: Status,
+}
+
+impl<T> Root<T> {
+    pub fn converged(&self) -> bool {
+        self.status == Status::Converged
+    }
+}
+
+/// Whether `a` and `b` are both strictly positive or both strictly negative.
+fn same_sign<T: Real>(a: T, b: T) -> bool {
+    (a > T::zero() && b > T::zero()) || (a < T::zero() && b < T::zero())
+}
+
+/// Whether `x` is neither infinite nor NaN.
+fn is_finite<T: Real>(x: T) -> bool {
+    x.abs() <= T::max_value()
+}
@@ -1,17 +1,21 @@
 //! Series
 
-use crate::{fraction::Ratio, ldexp, Digits};
+use crate::fraction::Ratio;
 use num::{traits::real::Real, FromPrimitive, Unsigned, Zero};
 
+/// [Kahan summation](https://en.wikipedia.org/wiki/Kahan_summation_algorithm) of the terms,
+/// stopping early once a nonzero term no longer changes the sum.
+///
 /// ```
 /// use quickmaths::series::kahan_sum;
 ///
 /// assert_eq!(kahan_sum([0.1; 10]), 1.);
+/// assert_eq!(kahan_sum([1., 0., 2.]), 3.);
 /// ```
 pub fn kahan_sum<T>(iter: T) -> T::Item
 where
     T: IntoIterator,
-    T::Item: Digits + Zero + FromPrimitive + Real,
+    T::Item: Zero + Real,
 {
     let mut iter = iter.into_iter();
     let mut result = if let Some(term) = iter.next() {
@@ -21,18 +25,17 @@ where
     };
 
     let mut carry = T::Item::zero();
-    let factor = T::Item::from_u32(ldexp(1, carry.precision_digits())).unwrap();
-
-    while let Some(term) = iter.next() {
+    for term in iter {
         let y = term - carry;
         let t = result + y;
-        carry = t - result;
-        carry = carry - y;
-        result = t;
 
-        if result.abs() >= (factor * term).abs() {
+        // Stop once the terms no longer change any part of the sum
+        if t == result && !term.is_zero() {
             break;
         }
+        carry = t - result;
+        carry = carry - y;
+        result = t;
     }
 
     result
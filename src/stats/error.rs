@@ -1,5 +1,4 @@
 use crate::{
-    epsilon,
     fraction::{upper_gamma_fraction, Ratio, Tiny},
     series::kahan_sum,
     Digits,
@@ -29,6 +28,15 @@ impl Default for ErrorFunction {
 
 impl ErrorFunction {
     /// Calculates the error function at `x`.
+    ///
+    /// ```
+    /// use quickmaths::stats::ErrorFunction;
+    /// use approx::assert_relative_eq;
+    ///
+    /// assert_relative_eq!(ErrorFunction::default().error(0.5), 0.5204998778130465, max_relative = 1e-15);
+    /// assert_relative_eq!(ErrorFunction::default().error(2.), 0.9953222650189527, max_relative = 1e-15);
+    /// assert_relative_eq!(ErrorFunction::default().complementary_error(5.), 1.5374597944280349e-12, max_relative = 1e-14);
+    /// ```
     pub fn error<T>(self, x: T) -> T
     where
         T: Tiny + Digits + FromPrimitive + FloatConst + Real + From<Ratio<i32>> + PartialOrd,
@@ -63,8 +71,8 @@ impl ErrorFunction {
                 Some(result)
             });
 
-            T::FRAC_2_SQRT_PI() * kahan_sum(f.take(self.sum_max_iters.into()))
-        } else if x > T::one() / epsilon() {
+            T::FRAC_2_SQRT_PI() * kahan_sum(f.take(self.sum_max_iters))
+        } else if x > T::one() / T::epsilon() {
             invert = !invert;
             (-x).exp() / (T::PI().sqrt() * value)
         } else {
@@ -74,7 +82,7 @@ impl ErrorFunction {
                 * upper_gamma_fraction(
                     Ratio::new(1, 2).into(),
                     x,
-                    epsilon(),
+                    T::epsilon(),
                     self.fraction_max_iters,
                 )
         };
@@ -11,3 +11,9 @@ pub trait Distribution {
 
     fn cdf(&self, x: &Self::Value, error: ErrorFunction) -> Self::Value;
 }
+
+/// Continuous distribution with a probability density function.
+pub trait Density: Distribution {
+    /// Probability density function at `x`.
+    fn pdf(&self, x: &Self::Value) -> Self::Value;
+}
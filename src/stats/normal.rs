@@ -1,4 +1,4 @@
-use super::{Distribution, ErrorFunction};
+use super::{Density, Distribution, ErrorFunction};
 use crate::{
     fraction::{Ratio, Tiny},
     Digits,
@@ -8,6 +8,7 @@ use num::{
     traits::{real::Real, FloatConst},
     FromPrimitive, One, Zero,
 };
+use rand::{distributions::Standard, Rng};
 
 pub struct NormalDistribution<T> {
     mean: T,
@@ -44,3 +45,46 @@ where
         one_half * error.complementary_error((&self.mean - x) / (self.std_deviation * T::SQRT_2()))
     }
 }
+
+impl<T> Density for NormalDistribution<T>
+where
+    T: Tiny + Digits + FromPrimitive + FloatConst + Real + From<Ratio<i32>> + PartialOrd,
+    for<'t> &'t T: Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// ```
+    /// use quickmaths::stats::{Density, NormalDistribution};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let normal = NormalDistribution::standard();
+    /// assert_relative_eq!(normal.pdf(&0.), 0.3989422804014327);
+    /// ```
+    fn pdf(&self, x: &Self::Value) -> Self::Value {
+        let z = (&self.mean - x) / self.std_deviation;
+        let one_half: T = Ratio::new(1, 2).into();
+        (-one_half * z * z).exp() / (self.std_deviation * (T::PI() + T::PI()).sqrt())
+    }
+}
+
+/// Draw samples using the [Box–Muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform).
+///
+/// ```
+/// use quickmaths::stats::NormalDistribution;
+/// use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0);
+/// let normal = NormalDistribution::new(10., 2.);
+/// let mean = normal.sample_iter(&mut rng).take(10_000).sum::<f64>() / 10_000.;
+/// assert!((mean - 10.).abs() < 0.1);
+/// ```
+impl<T> rand::distributions::Distribution<T> for NormalDistribution<T>
+where
+    T: Real + FloatConst + FromPrimitive,
+    Standard: rand::distributions::Distribution<T>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        let u1: T = rng.gen();
+        let u2: T = rng.gen();
+        let radius = (-T::from_u8(2).unwrap() * (T::one() - u1).ln()).sqrt();
+        self.mean + self.std_deviation * radius * (T::PI() * T::from_u8(2).unwrap() * u2).cos()
+    }
+}